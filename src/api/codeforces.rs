@@ -2,221 +2,1495 @@
 //   Imports and Dependencies
 // =====================
 
-use reqwest::{header, Client};
+use crate::api::rate_limit::RateLimiter;
+use rand::Rng;
+use reqwest::{header, Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use sha2::{Digest, Sha512};
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 // =====================
-//   API Problem Response Structures
+//   Client Configuration
 // =====================
 
-// Main response structure from Codeforces API
+// Default values used when the environment does not override them
+const DEFAULT_BASE_URL: &str = "https://codeforces.com/api";
+const DEFAULT_USER_AGENT: &str = "discord-bot/1.0";
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 5;
+const DEFAULT_RATE_LIMIT_BURST: u32 = 1;
+const DEFAULT_RATE_LIMIT_INTERVAL_MS: u64 = 2000;
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_BACKOFF_BASE_MS: u64 = 2000;
+const DEFAULT_BACKOFF_MAX_MS: u64 = 30000;
+
+// Handles sent per user.info call - keeps the URL comfortably short
+const USER_INFO_BATCH_SIZE: usize = 300;
+
+// Settings used to build a CodeforcesClient
+#[derive(Debug, Clone)]
+pub struct CodeforcesConfig {
+    pub base_url: String,
+    pub user_agent: String,
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub rate_limit_burst: u32,
+    pub rate_limit_interval: Duration,
+    pub retry: RetryPolicy,
+    // API key/secret used to sign requests (private groups, mashups, gym standings)
+    pub credentials: Option<ApiCredentials>,
+}
+
+// Retry settings for throttled or temporarily unavailable responses
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff_base: Duration,
+    pub backoff_max: Duration,
+}
+
+impl RetryPolicy {
+    // Exponential backoff with jitter: base * 2^attempt (+ up to 25%), capped at backoff_max
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .backoff_base
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.backoff_max);
+        let jitter = rand::rng().random_range(0.0..0.25);
+
+        exponential.mul_f64(1.0 + jitter).min(self.backoff_max)
+    }
+}
+
+impl Default for CodeforcesConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            rate_limit_burst: DEFAULT_RATE_LIMIT_BURST,
+            rate_limit_interval: Duration::from_millis(DEFAULT_RATE_LIMIT_INTERVAL_MS),
+            retry: RetryPolicy {
+                max_retries: DEFAULT_MAX_RETRIES,
+                backoff_base: Duration::from_millis(DEFAULT_BACKOFF_BASE_MS),
+                backoff_max: Duration::from_millis(DEFAULT_BACKOFF_MAX_MS),
+            },
+            credentials: None,
+        }
+    }
+}
+
+impl CodeforcesConfig {
+    // Build the configuration from CODEFORCES_* environment variables,
+    // falling back to the defaults for anything missing or unparsable
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let number = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let secs = |name: &str, default: Duration| {
+            number(name).map(Duration::from_secs).unwrap_or(default)
+        };
+        let millis = |name: &str, default: Duration| {
+            number(name).map(Duration::from_millis).unwrap_or(default)
+        };
+
+        Self {
+            base_url: env::var("CODEFORCES_API_URL").unwrap_or(defaults.base_url),
+            user_agent: env::var("CODEFORCES_USER_AGENT").unwrap_or(defaults.user_agent),
+            timeout: secs("CODEFORCES_TIMEOUT_SECS", defaults.timeout),
+            connect_timeout: secs("CODEFORCES_CONNECT_TIMEOUT_SECS", defaults.connect_timeout),
+            rate_limit_burst: number("CODEFORCES_RATE_LIMIT_BURST")
+                .and_then(|burst| u32::try_from(burst).ok())
+                .unwrap_or(defaults.rate_limit_burst),
+            rate_limit_interval: millis(
+                "CODEFORCES_RATE_LIMIT_INTERVAL_MS",
+                defaults.rate_limit_interval,
+            ),
+            retry: RetryPolicy {
+                max_retries: number("CODEFORCES_MAX_RETRIES")
+                    .and_then(|retries| u32::try_from(retries).ok())
+                    .unwrap_or(defaults.retry.max_retries),
+                backoff_base: millis("CODEFORCES_BACKOFF_BASE_MS", defaults.retry.backoff_base),
+                backoff_max: millis("CODEFORCES_BACKOFF_MAX_MS", defaults.retry.backoff_max),
+            },
+            credentials: ApiCredentials::from_env(),
+        }
+    }
+}
+
+// =====================
+//   Authenticated Requests
+// =====================
+
+// Codeforces API key pair (generated at https://codeforces.com/settings/api)
+#[derive(Clone)]
+pub struct ApiCredentials {
+    pub key: String,
+    pub secret: String,
+}
+
+// Never print the secret in logs
+impl fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("key", &self.key)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl ApiCredentials {
+    // Read CODEFORCES_API_KEY/CODEFORCES_API_SECRET; both must be set and non-empty
+    pub fn from_env() -> Option<Self> {
+        let key = env::var("CODEFORCES_API_KEY").ok()?.trim().to_string();
+        let secret = env::var("CODEFORCES_API_SECRET").ok()?.trim().to_string();
+
+        if key.is_empty() || secret.is_empty() {
+            return None;
+        }
+
+        Some(Self { key, secret })
+    }
+
+    // Add apiKey, time and apiSig to the parameters of any method call
+    pub fn sign(&self, method: &str, params: &[(&str, String)]) -> Vec<(String, String)> {
+        let time = chrono::Utc::now().timestamp();
+        let rand: String = {
+            let mut rng = rand::rng();
+            (0..6)
+                .map(|_| char::from(b'0' + rng.random_range(0..10u8)))
+                .collect()
+        };
+
+        self.sign_with(method, params, time, &rand)
+    }
+
+    // Deterministic part of the signature: apiSig = rand + sha512("rand/method?params#secret"),
+    // where params (including apiKey and time) are sorted by name, then by value
+    fn sign_with(
+        &self,
+        method: &str,
+        params: &[(&str, String)],
+        time: i64,
+        rand: &str,
+    ) -> Vec<(String, String)> {
+        let mut signed: Vec<(String, String)> = params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        signed.push(("apiKey".to_string(), self.key.clone()));
+        signed.push(("time".to_string(), time.to_string()));
+        signed.sort();
+
+        let query = signed
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("&");
+        let digest = Sha512::digest(format!("{}/{}?{}#{}", rand, method, query, self.secret));
+
+        signed.push(("apiSig".to_string(), format!("{}{:x}", rand, digest)));
+        signed
+    }
+}
+
+// =====================
+//   Codeforces Client
+// =====================
+
+// Shared Codeforces API client - owns a single connection pool and is cheap to clone.
+// Clones share the same rate limiter, so every caller is throttled globally.
+#[derive(Debug, Clone)]
+pub struct CodeforcesClient {
+    http: Client,
+    base_url: String,
+    limiter: Arc<RateLimiter>,
+    retry: RetryPolicy,
+    credentials: Option<ApiCredentials>,
+}
+
+// Envelope wrapping every Codeforces API response
 #[derive(Deserialize, Debug)]
-pub struct Codeforces {
-    pub status: String,
-    pub result: ResultData,
+struct ApiResponse<T> {
+    status: String,
+    comment: Option<String>,
+    result: Option<T>,
+}
+
+impl<T> ApiResponse<T> {
+    fn is_call_limit_exceeded(&self) -> bool {
+        self.status == "FAILED"
+            && self
+                .comment
+                .as_deref()
+                .is_some_and(|comment| comment.to_lowercase().contains("limit exceeded"))
+    }
+}
+
+// =====================
+//   Codeforces Error Type
+// =====================
+
+// Every way a Codeforces API call can fail, so callers can answer precisely
+#[derive(Debug)]
+pub enum CodeforcesError {
+    // The requested handle/contest/problem does not exist
+    NotFound { comment: String },
+    // Still throttled after all retries were spent
+    RateLimited,
+    // The API answered `status: FAILED` for any other reason
+    ApiFailed { comment: String },
+    // Connection or HTTP-level failure
+    Network(reqwest::Error),
+    // The response body did not match the expected shape
+    Decode(serde_json::Error),
+    // The request exceeded the configured timeout
+    Timeout,
+}
+
+impl CodeforcesError {
+    // Classify a `status: FAILED` comment returned by the API
+    fn from_comment(comment: String) -> Self {
+        let lower = comment.to_lowercase();
+
+        if lower.contains("not found") {
+            CodeforcesError::NotFound { comment }
+        } else if lower.contains("limit exceeded") {
+            CodeforcesError::RateLimited
+        } else {
+            CodeforcesError::ApiFailed { comment }
+        }
+    }
+}
+
+impl fmt::Display for CodeforcesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodeforcesError::NotFound { comment } => write!(f, "not found: {}", comment),
+            CodeforcesError::RateLimited => write!(f, "Codeforces call limit exceeded"),
+            CodeforcesError::ApiFailed { comment } => {
+                write!(f, "Codeforces API failed: {}", comment)
+            }
+            CodeforcesError::Network(e) => write!(f, "network error: {}", e),
+            CodeforcesError::Decode(e) => write!(f, "invalid Codeforces response: {}", e),
+            CodeforcesError::Timeout => write!(f, "Codeforces request timed out"),
+        }
+    }
+}
+
+impl std::error::Error for CodeforcesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CodeforcesError::Network(e) => Some(e),
+            CodeforcesError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
-// Result data containing the array of problems
+impl From<reqwest::Error> for CodeforcesError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            CodeforcesError::Timeout
+        } else {
+            CodeforcesError::Network(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for CodeforcesError {
+    fn from(e: serde_json::Error) -> Self {
+        CodeforcesError::Decode(e)
+    }
+}
+
+impl CodeforcesClient {
+    // Create a client from an explicit configuration
+    pub fn new(config: CodeforcesConfig) -> Result<Self, reqwest::Error> {
+        let http = Client::builder()
+            .user_agent(config.user_agent)
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()?;
+
+        Ok(Self {
+            http,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            limiter: Arc::new(RateLimiter::new(
+                config.rate_limit_burst,
+                config.rate_limit_interval,
+            )),
+            retry: config.retry,
+            credentials: config.credentials,
+        })
+    }
+
+    // Create a client configured from the environment
+    pub fn from_env() -> Result<Self, reqwest::Error> {
+        Self::new(CodeforcesConfig::from_env())
+    }
+
+    // Whether requests are signed with an API key
+    pub fn is_authenticated(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    // Build the full URL for an API method (e.g. "user.info")
+    fn method_url(&self, method: &str) -> String {
+        format!("{}/{}", self.base_url, method)
+    }
+
+    // =====================
+    //   Throttled Request Execution
+    // =====================
+
+    // Call an API method through the rate limiter, retrying with exponential backoff
+    // on HTTP 429/503 and on "Call limit exceeded" failures. Requests are signed
+    // whenever API credentials are configured.
+    // Returns the `result` field of the response envelope.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &[(&str, String)],
+    ) -> Result<T, CodeforcesError> {
+        let mut attempt = 0;
+
+        loop {
+            // Wait for our turn in the shared bucket
+            self.limiter.acquire().await;
+
+            let request = self.http.get(self.method_url(method));
+
+            // Signatures embed the current time, so every attempt is signed again
+            let request = match &self.credentials {
+                Some(credentials) => request.query(&credentials.sign(method, params)),
+                None => request.query(params),
+            };
+
+            let response = request.send().await?;
+
+            let status = response.status();
+            let can_retry = attempt < self.retry.max_retries;
+
+            // Throttled or temporarily unavailable - back off and try again
+            if can_retry
+                && (status == StatusCode::TOO_MANY_REQUESTS
+                    || status == StatusCode::SERVICE_UNAVAILABLE)
+            {
+                let delay = retry_after(&response).unwrap_or_else(|| self.retry.delay(attempt));
+                println!(
+                    "Codeforces {} returned {}, retrying in {:?}",
+                    method, status, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            let body = response.bytes().await?;
+
+            // Error pages (HTML from a proxy, empty bodies) are not worth decoding
+            let envelope = match serde_json::from_slice::<ApiResponse<T>>(&body) {
+                Ok(envelope) => envelope,
+                Err(_) if status == StatusCode::TOO_MANY_REQUESTS => {
+                    return Err(CodeforcesError::RateLimited)
+                }
+                Err(_) if !status.is_success() => {
+                    return Err(CodeforcesError::ApiFailed {
+                        comment: format!("HTTP {}", status),
+                    })
+                }
+                Err(e) => return Err(CodeforcesError::Decode(e)),
+            };
+
+            // Codeforces also reports throttling as `status: FAILED` with a comment
+            if can_retry && envelope.is_call_limit_exceeded() {
+                let delay = self.retry.delay(attempt);
+                println!(
+                    "Codeforces {} call limit exceeded, retrying in {:?}",
+                    method, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if envelope.status != "OK" {
+                return Err(CodeforcesError::from_comment(
+                    envelope
+                        .comment
+                        .unwrap_or_else(|| format!("HTTP {}", status)),
+                ));
+            }
+
+            return envelope
+                .result
+                .ok_or_else(|| CodeforcesError::Decode(serde::de::Error::missing_field("result")));
+        }
+    }
+}
+
+// Extract the handle from "handles: User with handle XYZ not found"
+fn missing_handle(comment: &str) -> Option<&str> {
+    let (_, rest) = comment.split_once("User with handle ")?;
+    let (handle, _) = rest.split_once(" not found")?;
+    Some(handle.trim())
+}
+
+// Read a Retry-After header expressed in seconds, if the server sent one
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+// =====================
+//   API Problem Response Structures
+// =====================
+
+// Result of problemset.problems containing the array of problems
 #[derive(Deserialize, Debug)]
-pub struct ResultData {
+#[serde(rename_all = "camelCase")]
+pub struct Problemset {
     pub problems: Vec<Problem>,
+    #[serde(default)]
+    pub problem_statistics: Vec<ProblemStatistics>,
+}
+
+// Number of users who solved a problem (parallel to `problems`)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemStatistics {
+    pub contest_id: Option<u32>,
+    pub index: String,
+    pub solved_count: u32,
 }
 
 // Individual problem structure with all relevant fields
-#[derive(Deserialize, Debug)]
+// (shared by problemset.problems and the problem embedded in each submission)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Problem {
     pub contest_id: Option<u32>,
+    pub problemset_name: Option<String>,
     pub index: String,
     pub name: String,
     #[serde(rename = "type")]
     pub problem_type: String,
     pub points: Option<f32>,
     pub rating: Option<u32>,
+    #[serde(default)]
     pub tags: Vec<String>,
+    // Merged in from problemStatistics by get_problems
+    #[serde(default)]
+    pub solved_count: Option<u32>,
 }
 
 // =====================
-//   Codeforces Problem API Integration
+//   Problem Filtering
 // =====================
 
-// Main function to fetch problems from Codeforces API
-// Supports optional rating range filtering (min_rating and max_rating)
-pub async fn get_problems(
-    min_rating: Option<u32>,
-    max_rating: Option<u32>,
-) -> Result<Codeforces, Box<dyn std::error::Error + Send + Sync>> {
-    // =====================
-    //   HTTP Client Setup
-    // =====================
+// Criteria a problem must meet; empty fields don't restrict anything
+#[derive(Debug, Clone, Default)]
+pub struct ProblemFilter {
+    pub min_rating: Option<u32>,
+    pub max_rating: Option<u32>,
+    // Problems must have every one of these tags
+    pub tags: Vec<String>,
+    // Problems must have none of these tags
+    pub excluded_tags: Vec<String>,
+    pub min_solved_count: Option<u32>,
+    pub min_contest_id: Option<u32>,
+    pub max_contest_id: Option<u32>,
+    // "PROGRAMMING" or "QUESTION"
+    pub problem_type: Option<String>,
+}
 
-    // Initialize HTTP client for API requests
-    let client = Client::new();
-    let url = "https://codeforces.com/api/problemset.problems";
+impl ProblemFilter {
+    // Filter on a rating range only
+    pub fn rating_range(min_rating: Option<u32>, max_rating: Option<u32>) -> Self {
+        Self {
+            min_rating,
+            max_rating,
+            ..Self::default()
+        }
+    }
 
-    // =====================
-    //   API Request Execution
-    // =====================
+    // Check a problem against every criterion.
+    // Unrated problems never match once a rating bound is given.
+    pub fn matches(&self, problem: &Problem) -> bool {
+        if self.min_rating.is_some() || self.max_rating.is_some() {
+            match problem.rating {
+                Some(rating) => {
+                    if self.min_rating.is_some_and(|min| rating < min)
+                        || self.max_rating.is_some_and(|max| rating > max)
+                    {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
 
-    // Make GET request to Codeforces API with User-Agent header
-    let mut response = client
-        .get(url)
-        .header(header::USER_AGENT, "discord-bot/1.0")
-        .send()
-        .await?
-        .json::<Codeforces>()
-        .await?;
+        let has_tag = |tag: &String| problem.tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+        if !self.tags.iter().all(has_tag) || self.excluded_tags.iter().any(has_tag) {
+            return false;
+        }
 
-    // =====================
-    //   Rating-Based Filtering
-    // =====================
+        if let Some(min_solved) = self.min_solved_count {
+            if problem.solved_count.unwrap_or(0) < min_solved {
+                return false;
+            }
+        }
 
-    // Filter problems by rating if ranges are specified
-    if min_rating.is_some() || max_rating.is_some() {
-        response.result.problems.retain(|problem| {
-            if let Some(rating) = problem.rating {
-                // Check rating against specified bounds
-                if let Some(min) = min_rating {
-                    if let Some(max) = max_rating {
-                        // Both min and max specified
-                        rating >= min && rating <= max
-                    } else {
-                        // Only min specified
-                        rating >= min
+        if self.min_contest_id.is_some() || self.max_contest_id.is_some() {
+            match problem.contest_id {
+                Some(id) => {
+                    if self.min_contest_id.is_some_and(|min| id < min)
+                        || self.max_contest_id.is_some_and(|max| id > max)
+                    {
+                        return false;
                     }
-                } else if let Some(max) = max_rating {
-                    // Only max specified
-                    rating <= max
-                } else {
-                    // Neither specified (shouldn't happen but handle gracefully)
-                    true
                 }
-            } else {
-                // Skip problems without rating when filtering is requested
-                false
+                None => return false,
             }
-        });
+        }
+
+        self.problem_type
+            .as_deref()
+            .is_none_or(|kind| problem.problem_type.eq_ignore_ascii_case(kind))
     }
+}
 
-    // =====================
-    //   Response Return
-    // =====================
+// =====================
+//   Codeforces Problem API Integration
+// =====================
 
-    // Return filtered response
-    Ok(response)
+impl CodeforcesClient {
+    // Fetch problems from Codeforces API.
+    // Required tags are filtered server-side; every other criterion locally.
+    pub async fn get_problems(
+        &self,
+        filter: &ProblemFilter,
+    ) -> Result<Problemset, CodeforcesError> {
+        // =====================
+        //   API Request Execution
+        // =====================
+
+        let mut params = Vec::new();
+        if !filter.tags.is_empty() {
+            params.push(("tags", filter.tags.join(";")));
+        }
+
+        // Make throttled GET request through the shared client
+        let mut problemset: Problemset = self.call("problemset.problems", &params).await?;
+
+        // =====================
+        //   Merge Problem Statistics
+        // =====================
+
+        let solved_counts: HashMap<(Option<u32>, &str), u32> = problemset
+            .problem_statistics
+            .iter()
+            .map(|stat| ((stat.contest_id, stat.index.as_str()), stat.solved_count))
+            .collect();
+
+        for problem in &mut problemset.problems {
+            problem.solved_count = solved_counts
+                .get(&(problem.contest_id, problem.index.as_str()))
+                .copied();
+        }
+
+        // =====================
+        //   Local Filtering
+        // =====================
+
+        problemset
+            .problems
+            .retain(|problem| filter.matches(problem));
+
+        // =====================
+        //   Response Return
+        // =====================
+
+        // Return filtered problemset
+        Ok(problemset)
+    }
 }
 
 // =====================
 //   API User Info Response Structures
 // =====================
 
-#[derive(Deserialize, Debug)]
-pub struct UserInfo {
-    pub status: String,
-    pub result: Vec<UserInfoData>,
-}
-
-#[derive(Deserialize, Debug)]
+// Profile returned by user.info
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserInfoData {
     pub handle: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
     pub country: Option<String>,
+    pub city: Option<String>,
+    pub organization: Option<String>,
+    pub contribution: Option<i32>,
     pub rank: Option<String>,
     pub rating: Option<i32>,
-    pub maxRank: Option<String>,
-    pub maxRating: Option<i32>,
+    pub max_rank: Option<String>,
+    pub max_rating: Option<i32>,
+    pub last_online_time_seconds: Option<i64>,
+    pub registration_time_seconds: Option<i64>,
+    pub friend_of_count: Option<u32>,
+    pub avatar: Option<String>,
+    pub title_photo: Option<String>,
 }
 
 // =====================
 //   API User Status Response Structures
 // =====================
 
-#[derive(Deserialize, Debug)]
-pub struct UserStatus {
-    pub status: String,
-    pub result: Vec<Submission>,
-}
-
-#[derive(Deserialize, Debug)]
+// Single submission returned by user.status
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Submission {
-    // pub id: u64,
-    // pub contestId: Option<u32>,
-    // pub creationTimeSeconds: Option<u64>,
-    // pub relativeTimeSeconds: Option<i64>,
-    pub problem: SubmissionProblem,
-    // pub author: Author,
-    // pub programmingLanguage: Option<String>,
+    pub id: u64,
+    pub contest_id: Option<u32>,
+    pub creation_time_seconds: i64,
+    pub relative_time_seconds: Option<i64>,
+    pub problem: Problem,
+    pub author: Author,
+    pub programming_language: String,
     pub verdict: Option<String>,
-    // pub testset: Option<String>,
-    // pub passedTestCount: Option<u32>,
-    // pub timeConsumedMillis: Option<u32>,
-    // pub memoryConsumedBytes: Option<u64>,
+    pub testset: Option<String>,
+    #[serde(default)]
+    pub passed_test_count: u32,
+    #[serde(default)]
+    pub time_consumed_millis: u32,
+    #[serde(default)]
+    pub memory_consumed_bytes: u64,
+    pub points: Option<f32>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct SubmissionProblem {
-    pub contestId: Option<u32>,
-    pub index: String,
-    // pub name: String,
-    // #[serde(rename = "type")]
-    // pub problem_type: Option<String>,
-    // pub points: Option<f32>,
-    // pub rating: Option<u32>,
-    // pub tags: Option<Vec<String>>,
-}
-
-// #[derive(Deserialize, Debug)]
-// pub struct Author {
-//     pub contestId: Option<u32>,
-//     pub participantId: Option<u64>,
-//     pub members: Vec<Member>,
-//     pub participantType: Option<String>,
-//     pub ghost: Option<bool>,
-//     pub startTimeSeconds: Option<u64>,
-// }
-
-// #[derive(Deserialize, Debug)]
-// pub struct Member {
-//     pub handle: String,
-// }
-
-pub async fn get_user_info(
-    handle: &str,
-) -> Result<UserInfo, Box<dyn std::error::Error + Send + Sync>> {
-    // Initialize HTTP client for API requests
-    let client = Client::new();
-    let url = format!("https://codeforces.com/api/user.info?handles={}", handle);
-
-    // Make GET request to Codeforces API with User-Agent header
-    let response = client
-        .get(&url)
-        .header(header::USER_AGENT, "discord-bot/1.0")
-        .send()
-        .await?;
-
-    let user_info: UserInfo = response.json().await?;
-
-    Ok(user_info)
-}
-
-// =====================
-//   Codeforces User Status API Integration
-// =====================
-
-// Function to fetch user submissions from Codeforces API
-pub async fn get_user_status(
-    handle: &str,
-) -> Result<UserStatus, Box<dyn std::error::Error + Send + Sync>> {
-    // Initialize HTTP client for API requests
-    let client = Client::new();
-    let url = format!("https://codeforces.com/api/user.status?handle={}", handle);
-
-    // Make GET request to Codeforces API with User-Agent header
-    let response = client
-        .get(&url)
-        .header(header::USER_AGENT, "discord-bot/1.0")
-        .send()
-        .await?;
-
-    let user_status: UserStatus = response.json().await?;
-
-    Ok(user_status)
+impl Submission {
+    // Whether the submission was accepted
+    pub fn is_accepted(&self) -> bool {
+        self.verdict.as_deref() == Some("OK")
+    }
+
+    // Whether the submission failed to compile
+    pub fn is_compilation_error(&self) -> bool {
+        self.verdict.as_deref() == Some("COMPILATION_ERROR")
+    }
+}
+
+// Party (individual or team) that made a submission
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Author {
+    pub contest_id: Option<u32>,
+    pub participant_id: Option<u64>,
+    pub members: Vec<Member>,
+    pub participant_type: String,
+    pub team_id: Option<u64>,
+    pub team_name: Option<String>,
+    #[serde(default)]
+    pub ghost: bool,
+    pub room: Option<u32>,
+    pub start_time_seconds: Option<i64>,
+}
+
+// Member of a party
+#[derive(Deserialize, Debug, Clone)]
+pub struct Member {
+    pub handle: String,
+    pub name: Option<String>,
+}
+
+// =====================
+//   Codeforces User API Integration
+// =====================
+
+impl CodeforcesClient {
+    // Fetch profile information for a single handle
+    pub async fn get_user_info(&self, handle: &str) -> Result<UserInfoData, CodeforcesError> {
+        let users: Vec<UserInfoData> = self
+            .call("user.info", &[("handles", handle.to_string())])
+            .await?;
+
+        users
+            .into_iter()
+            .next()
+            .ok_or_else(|| CodeforcesError::NotFound {
+                comment: format!("handles: User with handle {} not found", handle),
+            })
+    }
+
+    // Fetch profile information for many handles in as few calls as possible.
+    // Results are keyed by the lowercased handle that was requested; handles that
+    // don't exist on Codeforces are simply missing from the map.
+    pub async fn get_users_info(
+        &self,
+        handles: &[String],
+    ) -> Result<HashMap<String, UserInfoData>, CodeforcesError> {
+        // Codeforces handles are case-insensitive, so ask for each one only once
+        let mut unique: Vec<&str> = Vec::new();
+        for handle in handles.iter().map(|h| h.trim()).filter(|h| !h.is_empty()) {
+            if !unique.iter().any(|seen| seen.eq_ignore_ascii_case(handle)) {
+                unique.push(handle);
+            }
+        }
+
+        let mut users = HashMap::with_capacity(unique.len());
+
+        for chunk in unique.chunks(USER_INFO_BATCH_SIZE) {
+            let mut pending: Vec<&str> = chunk.to_vec();
+
+            // A single unknown handle fails the whole call, so drop it and ask again
+            while !pending.is_empty() {
+                match self
+                    .call::<Vec<UserInfoData>>("user.info", &[("handles", pending.join(";"))])
+                    .await
+                {
+                    Ok(result) => {
+                        // Results come back in request order
+                        for (requested, user) in pending.iter().zip(result) {
+                            users.insert(requested.to_lowercase(), user);
+                        }
+                        break;
+                    }
+                    Err(CodeforcesError::NotFound { comment }) => {
+                        let missing = missing_handle(&comment);
+                        let before = pending.len();
+                        pending.retain(|handle| {
+                            missing.is_none_or(|missing| !handle.eq_ignore_ascii_case(missing))
+                        });
+
+                        // Could not tell which handle was missing - give up on this chunk
+                        if pending.len() == before {
+                            return Err(CodeforcesError::NotFound { comment });
+                        }
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(users)
+    }
+
+    // Fetch user submissions from Codeforces API, newest first.
+    // `from` is 1-based; pass `from`/`count` to page through the history
+    // instead of downloading it all.
+    pub async fn get_user_status(
+        &self,
+        handle: &str,
+        from: Option<u32>,
+        count: Option<u32>,
+    ) -> Result<Vec<Submission>, CodeforcesError> {
+        let mut params = vec![("handle", handle.to_string())];
+        if let Some(from) = from {
+            params.push(("from", from.max(1).to_string()));
+        }
+        if let Some(count) = count {
+            params.push(("count", count.to_string()));
+        }
+
+        self.call("user.status", &params).await
+    }
+
+    // Fetch the rating history of a user, oldest contest first
+    pub async fn get_user_rating(
+        &self,
+        handle: &str,
+    ) -> Result<Vec<RatingChange>, CodeforcesError> {
+        self.call("user.rating", &[("handle", handle.to_string())])
+            .await
+    }
+}
+
+// =====================
+//   API Contest Response Structures
+// =====================
+
+// Contest returned by contest.list and contest.standings
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Contest {
+    pub id: u32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub contest_type: String,
+    pub phase: String,
+    #[serde(default)]
+    pub frozen: bool,
+    pub duration_seconds: i64,
+    pub start_time_seconds: Option<i64>,
+    pub relative_time_seconds: Option<i64>,
+    pub prepared_by: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<u32>,
+    pub kind: Option<String>,
+    pub icpc_region: Option<String>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+    pub season: Option<String>,
+}
+
+// Result of contest.standings
+#[derive(Deserialize, Debug, Clone)]
+pub struct Standings {
+    pub contest: Contest,
+    pub problems: Vec<Problem>,
+    pub rows: Vec<RanklistRow>,
+}
+
+// One participant's line in the standings
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RanklistRow {
+    pub party: Author,
+    pub rank: u32,
+    pub points: f32,
+    pub penalty: i32,
+    pub successful_hack_count: u32,
+    pub unsuccessful_hack_count: u32,
+    pub problem_results: Vec<ProblemResult>,
+    pub last_submission_time_seconds: Option<i64>,
+}
+
+// A participant's result on one problem of the standings
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemResult {
+    pub points: f32,
+    pub penalty: Option<i32>,
+    pub rejected_attempt_count: u32,
+    #[serde(rename = "type")]
+    pub result_type: String,
+    pub best_submission_time_seconds: Option<i64>,
+}
+
+// Rating change of a participant after a contest
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingChange {
+    pub contest_id: u32,
+    pub contest_name: String,
+    pub handle: String,
+    pub rank: u32,
+    pub rating_update_time_seconds: i64,
+    pub old_rating: i32,
+    pub new_rating: i32,
+}
+
+impl RatingChange {
+    // Rating gained (or lost) in this contest
+    pub fn delta(&self) -> i32 {
+        self.new_rating - self.old_rating
+    }
+}
+
+// Optional parameters for contest.standings
+#[derive(Debug, Clone, Default)]
+pub struct StandingsQuery {
+    // 1-based index of the first row to return
+    pub from: Option<u32>,
+    pub count: Option<u32>,
+    // Only return rows for these handles
+    pub handles: Vec<String>,
+    pub room: Option<u32>,
+    // Include out-of-competition, virtual and practice participants
+    pub show_unofficial: bool,
+}
+
+// =====================
+//   Codeforces Contest API Integration
+// =====================
+
+impl CodeforcesClient {
+    // List all contests, or gym contests when `gym` is true
+    pub async fn get_contest_list(&self, gym: bool) -> Result<Vec<Contest>, CodeforcesError> {
+        self.call("contest.list", &[("gym", gym.to_string())]).await
+    }
+
+    // Fetch the standings of a contest, optionally filtered by handles
+    pub async fn get_contest_standings(
+        &self,
+        contest_id: u32,
+        query: &StandingsQuery,
+    ) -> Result<Standings, CodeforcesError> {
+        let mut params = vec![("contestId", contest_id.to_string())];
+        if let Some(from) = query.from {
+            params.push(("from", from.max(1).to_string()));
+        }
+        if let Some(count) = query.count {
+            params.push(("count", count.to_string()));
+        }
+        if !query.handles.is_empty() {
+            params.push(("handles", query.handles.join(";")));
+        }
+        if let Some(room) = query.room {
+            params.push(("room", room.to_string()));
+        }
+        params.push(("showUnofficial", query.show_unofficial.to_string()));
+
+        self.call("contest.standings", &params).await
+    }
+
+    // Fetch the rating changes caused by a rated contest
+    pub async fn get_contest_rating_changes(
+        &self,
+        contest_id: u32,
+    ) -> Result<Vec<RatingChange>, CodeforcesError> {
+        self.call(
+            "contest.ratingChanges",
+            &[("contestId", contest_id.to_string())],
+        )
+        .await
+    }
+}
+
+// =====================
+//   Test Builders
+// =====================
+
+// API objects for the unit tests of code that consumes them.
+// Tests adjust the fields they care about with struct update syntax.
+#[cfg(test)]
+pub mod builders {
+    use super::*;
+
+    // Configuration pointed at a mock server, without throttling or retries
+    pub fn mock_config(base_url: String) -> CodeforcesConfig {
+        CodeforcesConfig {
+            base_url,
+            rate_limit_burst: 100,
+            rate_limit_interval: Duration::ZERO,
+            retry: RetryPolicy {
+                max_retries: 0,
+                backoff_base: Duration::ZERO,
+                backoff_max: Duration::ZERO,
+            },
+            ..CodeforcesConfig::default()
+        }
+    }
+
+    // Rated programming problem of a problemset contest
+    pub fn problem(contest_id: u32, index: &str) -> Problem {
+        Problem {
+            contest_id: Some(contest_id),
+            problemset_name: None,
+            index: index.to_string(),
+            name: format!("Problem {}{}", contest_id, index),
+            problem_type: "PROGRAMMING".to_string(),
+            points: None,
+            rating: Some(800),
+            tags: Vec::new(),
+            solved_count: None,
+        }
+    }
+
+    // Practice submission to a problem with the given verdict
+    pub fn submission(
+        id: u64,
+        problem: Problem,
+        verdict: &str,
+        creation_time_seconds: i64,
+    ) -> Submission {
+        Submission {
+            id,
+            contest_id: problem.contest_id,
+            creation_time_seconds,
+            relative_time_seconds: None,
+            problem,
+            author: Author {
+                contest_id: None,
+                participant_id: None,
+                members: vec![Member {
+                    handle: "someone".to_string(),
+                    name: None,
+                }],
+                participant_type: "PRACTICE".to_string(),
+                team_id: None,
+                team_name: None,
+                ghost: false,
+                room: None,
+                start_time_seconds: None,
+            },
+            programming_language: "GNU C++17".to_string(),
+            verdict: Some(verdict.to_string()),
+            testset: None,
+            passed_test_count: 0,
+            time_consumed_millis: 0,
+            memory_consumed_bytes: 0,
+            points: None,
+        }
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{
+        header, method, path, query_param, query_param_contains, query_param_is_missing,
+    };
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_config(server: &MockServer) -> CodeforcesConfig {
+        builders::mock_config(server.uri())
+    }
+
+    fn mock_client(server: &MockServer) -> CodeforcesClient {
+        CodeforcesClient::new(test_config(server)).expect("client should build")
+    }
+
+    // Client that retries up to `max_retries` times with a 1ms backoff
+    fn retrying_client(server: &MockServer, max_retries: u32) -> CodeforcesClient {
+        CodeforcesClient::new(CodeforcesConfig {
+            retry: RetryPolicy {
+                max_retries,
+                backoff_base: Duration::from_millis(1),
+                backoff_max: Duration::from_millis(1),
+            },
+            ..test_config(server)
+        })
+        .expect("client should build")
+    }
+
+    fn fixture(body: &'static str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_raw(body, "application/json")
+    }
+
+    // Respond with `response` to the first `times` rating change requests only
+    async fn mount_failures(server: &MockServer, response: ResponseTemplate, times: u64) {
+        Mock::given(method("GET"))
+            .and(path("/contest.ratingChanges"))
+            .respond_with(response)
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn api_signature_matches_reference() {
+        let credentials = ApiCredentials {
+            key: "xxx".to_string(),
+            secret: "yyy".to_string(),
+        };
+
+        let params = credentials.sign_with(
+            "contest.hacks",
+            &[("contestId", "566".to_string())],
+            1234567890,
+            "123456",
+        );
+
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["apiKey", "contestId", "time", "apiSig"]);
+        assert_eq!(
+            params[3].1,
+            "123456\
+             7f467d1cd837599d2f0dc9fd8beec8fad80ee7d02f0b65ad153a963bca2923de\
+             885e11c96cba96beceaba6dd7433d20c0cbb507b7615b3dccfb693b6163ccc94"
+        );
+    }
+
+    #[tokio::test]
+    async fn signed_requests_carry_api_key_and_signature() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.list"))
+            .and(query_param("apiKey", "xxx"))
+            .and(query_param_contains("apiSig", ""))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_list_gym.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CodeforcesClient::new(CodeforcesConfig {
+            credentials: Some(ApiCredentials {
+                key: "xxx".to_string(),
+                secret: "yyy".to_string(),
+            }),
+            ..test_config(&server)
+        })
+        .unwrap();
+
+        assert!(client.is_authenticated());
+        assert_eq!(client.get_contest_list(true).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn problems_merge_statistics_and_apply_filter() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/problemset.problems"))
+            .and(query_param("tags", "greedy"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/problemset_problems.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let filter = ProblemFilter {
+            min_rating: Some(900),
+            max_rating: Some(1600),
+            tags: vec!["greedy".to_string()],
+            excluded_tags: vec!["games".to_string()],
+            min_solved_count: Some(10_000),
+            ..ProblemFilter::default()
+        };
+        let problemset = mock_client(&server).get_problems(&filter).await.unwrap();
+
+        assert_eq!(problemset.problems.len(), 1);
+        assert_eq!(problemset.problems[0].name, "Slavic's Exam");
+        assert_eq!(problemset.problems[0].solved_count, Some(31250));
+    }
+
+    #[tokio::test]
+    async fn users_info_skips_unknown_handles() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user.info"))
+            .and(query_param("handles", "tourist;no_such_user;Petr"))
+            .respond_with(ResponseTemplate::new(400).set_body_raw(
+                r#"{"status":"FAILED","comment":"handles: User with handle no_such_user not found"}"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user.info"))
+            .and(query_param("handles", "tourist;Petr"))
+            .respond_with(fixture(
+                r#"{"status":"OK","result":[{"handle":"tourist","rating":3800},{"handle":"Petr","rating":3100}]}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let handles = ["tourist", "no_such_user", "Petr", "TOURIST"].map(String::from);
+        let users = mock_client(&server).get_users_info(&handles).await.unwrap();
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users["tourist"].rating, Some(3800));
+        assert_eq!(users["petr"].rating, Some(3100));
+    }
+
+    #[tokio::test]
+    async fn contest_list_passes_gym_flag() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.list"))
+            .and(query_param("gym", "true"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_list_gym.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let contests = mock_client(&server).get_contest_list(true).await.unwrap();
+
+        assert_eq!(contests.len(), 2);
+        assert_eq!(contests[0].id, 105_446);
+        assert_eq!(contests[0].contest_type, "ICPC");
+        assert_eq!(contests[0].phase, "FINISHED");
+        assert_eq!(contests[0].difficulty, Some(3));
+        assert_eq!(contests[1].prepared_by.as_deref(), Some("MikeMirzayanov"));
+    }
+
+    #[tokio::test]
+    async fn contest_standings_sends_filters_and_decodes_rows() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.standings"))
+            .and(query_param("contestId", "566"))
+            .and(query_param("from", "1"))
+            .and(query_param("count", "5"))
+            .and(query_param("handles", "tourist;Petr"))
+            .and(query_param("showUnofficial", "true"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_standings.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let query = StandingsQuery {
+            from: Some(1),
+            count: Some(5),
+            handles: vec!["tourist".to_string(), "Petr".to_string()],
+            show_unofficial: true,
+            ..StandingsQuery::default()
+        };
+        let standings = mock_client(&server)
+            .get_contest_standings(566, &query)
+            .await
+            .unwrap();
+
+        assert_eq!(standings.contest.id, 566);
+        assert_eq!(standings.problems.len(), 2);
+        assert_eq!(standings.problems[0].contest_id, Some(566));
+        assert_eq!(standings.rows.len(), 2);
+
+        let first = &standings.rows[0];
+        assert_eq!(first.rank, 1);
+        assert_eq!(first.party.members[0].handle, "tourist");
+        assert_eq!(first.party.participant_type, "CONTESTANT");
+        assert_eq!(first.problem_results[1].rejected_attempt_count, 1);
+        assert_eq!(
+            standings.rows[1].party.participant_type,
+            "OUT_OF_COMPETITION"
+        );
+    }
+
+    #[tokio::test]
+    async fn contest_rating_changes_decodes_deltas() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.ratingChanges"))
+            .and(query_param("contestId", "566"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_rating_changes.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let changes = mock_client(&server)
+            .get_contest_rating_changes(566)
+            .await
+            .unwrap();
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].handle, "tourist");
+        assert_eq!(changes[0].delta(), 63);
+        assert_eq!(changes[2].delta(), -41);
+    }
+
+    #[tokio::test]
+    async fn contest_standings_reports_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.standings"))
+            .respond_with(ResponseTemplate::new(400).set_body_raw(
+                include_str!("../../tests/fixtures/codeforces/contest_not_found.json"),
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let error = mock_client(&server)
+            .get_contest_standings(999_999, &StandingsQuery::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, CodeforcesError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn retries_throttled_and_unavailable_responses() {
+        let server = MockServer::start().await;
+        // Mounted first, so they answer before the successful mock
+        mount_failures(&server, ResponseTemplate::new(429), 1).await;
+        mount_failures(&server, ResponseTemplate::new(503), 1).await;
+        mount_failures(
+            &server,
+            ResponseTemplate::new(400).set_body_raw(
+                r#"{"status":"FAILED","comment":"Call limit exceeded"}"#,
+                "application/json",
+            ),
+            1,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/contest.ratingChanges"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_rating_changes.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let changes = retrying_client(&server, 3)
+            .get_contest_rating_changes(566)
+            .await
+            .unwrap();
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn gives_up_once_retries_are_spent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.ratingChanges"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let error = retrying_client(&server, 2)
+            .get_contest_rating_changes(566)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, CodeforcesError::RateLimited));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_failures() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contest.ratingChanges"))
+            .respond_with(ResponseTemplate::new(400).set_body_raw(
+                include_str!("../../tests/fixtures/codeforces/contest_not_found.json"),
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/contest.list"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("<html>oops</html>"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = retrying_client(&server, 3);
+
+        let error = client
+            .get_contest_rating_changes(999_999)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, CodeforcesError::NotFound { .. }));
+
+        let error = client.get_contest_list(false).await.unwrap_err();
+        assert!(matches!(error, CodeforcesError::ApiFailed { .. }));
+    }
+
+    #[tokio::test]
+    async fn uses_configured_base_url_and_user_agent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/contest.list"))
+            .and(header("user-agent", "olimp-bot-test/2.0"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/contest_list_gym.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // A trailing slash on the base URL must not double up in the method URL
+        let client = CodeforcesClient::new(CodeforcesConfig {
+            base_url: format!("{}/api/", server.uri()),
+            user_agent: "olimp-bot-test/2.0".to_string(),
+            ..test_config(&server)
+        })
+        .unwrap();
+
+        assert!(!client.is_authenticated());
+        assert_eq!(client.get_contest_list(false).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn user_status_sends_paging_parameters() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user.status"))
+            .and(query_param("handle", "someone"))
+            .and(query_param("from", "51"))
+            .and(query_param("count", "50"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/user_status.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let submissions = mock_client(&server)
+            .get_user_status("someone", Some(51), Some(50))
+            .await
+            .unwrap();
+
+        assert_eq!(submissions.len(), 2);
+        assert_eq!(submissions[0].id, 255_000_002);
+        assert!(submissions[0].is_accepted());
+        assert_eq!(submissions[0].problem.id().unwrap().to_string(), "467B");
+        assert_eq!(submissions[1].verdict.as_deref(), Some("WRONG_ANSWER"));
+        assert_eq!(submissions[1].author.members[0].handle, "someone");
+    }
+
+    #[tokio::test]
+    async fn user_status_without_paging_requests_the_whole_history() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user.status"))
+            .and(query_param("handle", "someone"))
+            .and(query_param_is_missing("from"))
+            .and(query_param_is_missing("count"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/user_status.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // `from` is 1-based, so 0 is sent as 1
+        Mock::given(method("GET"))
+            .and(path("/user.status"))
+            .and(query_param("from", "1"))
+            .and(query_param_is_missing("count"))
+            .respond_with(fixture(include_str!(
+                "../../tests/fixtures/codeforces/user_status.json"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        assert_eq!(
+            client
+                .get_user_status("someone", None, None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            client
+                .get_user_status("someone", Some(0), None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
 }
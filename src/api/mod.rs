@@ -4,3 +4,6 @@
 
 // Module declarations for external API integrations
 pub mod codeforces; // Codeforces API integration for fetching problems and contest data
+pub mod problem_cache; // In-memory + Postgres cache of the Codeforces problemset
+pub mod problem_id; // Canonical Codeforces problem identifiers (467B, 1850A1, gym ids, URLs)
+pub mod rate_limit; // Shared token-bucket limiter for outgoing API calls
@@ -0,0 +1,412 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::api::codeforces::{CodeforcesClient, CodeforcesError, Problem, ProblemFilter};
+use crate::api::problem_id::ProblemId;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+
+// =====================
+//   Cache Configuration
+// =====================
+
+// Default time a downloaded problemset stays fresh (6 hours)
+const DEFAULT_TTL_SECS: u64 = 6 * 60 * 60;
+
+// Rows per INSERT statement - keeps us well under Postgres' bind parameter limit
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+// =====================
+//   Problemset Cache
+// =====================
+
+// Snapshot of the whole problemset held in memory
+struct Snapshot {
+    problems: Arc<Vec<Problem>>,
+    fetched_at: DateTime<Utc>,
+}
+
+// In-memory problemset cache backed by the `cf_problems` table.
+// Commands read from memory; a background task refreshes it once the TTL expires.
+pub struct ProblemCache {
+    client: CodeforcesClient,
+    db: PgPool,
+    ttl: Duration,
+    snapshot: RwLock<Option<Snapshot>>,
+    // Ensures only one download/load runs at a time
+    refresh_lock: Mutex<()>,
+}
+
+impl ProblemCache {
+    // Create an empty cache
+    pub fn new(client: CodeforcesClient, db: PgPool, ttl: Duration) -> Self {
+        Self {
+            client,
+            db,
+            ttl,
+            snapshot: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    // Create an empty cache with the TTL taken from PROBLEM_CACHE_TTL_SECS
+    pub fn from_env(client: CodeforcesClient, db: PgPool) -> Self {
+        let ttl = env::var("PROBLEM_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_TTL_SECS);
+
+        Self::new(client, db, Duration::from_secs(ttl))
+    }
+
+    // How long a snapshot stays fresh
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    // =====================
+    //   Cache Reads
+    // =====================
+
+    // Get the cached problemset, loading it from the database or the API on first use.
+    // A stale snapshot is still served - the background task takes care of refreshing it.
+    pub async fn problems(&self) -> Result<Arc<Vec<Problem>>, CodeforcesError> {
+        if let Some(snapshot) = self.snapshot.read().await.as_ref() {
+            return Ok(Arc::clone(&snapshot.problems));
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+
+        // Another caller may have filled the cache while we waited for the lock
+        if let Some(snapshot) = self.snapshot.read().await.as_ref() {
+            return Ok(Arc::clone(&snapshot.problems));
+        }
+
+        match self.load_snapshot().await {
+            Ok(Some(snapshot)) => {
+                let problems = Arc::clone(&snapshot.problems);
+                *self.snapshot.write().await = Some(snapshot);
+                return Ok(problems);
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Error loading cached problems from database: {}", e),
+        }
+
+        self.download().await
+    }
+
+    // Get the cached problems matching a filter
+    pub async fn problems_matching(
+        &self,
+        filter: &ProblemFilter,
+    ) -> Result<Vec<Problem>, CodeforcesError> {
+        let problems = self.problems().await?;
+
+        Ok(problems
+            .iter()
+            .filter(|problem| filter.matches(problem))
+            .cloned()
+            .collect())
+    }
+
+    // Look up a single problem by id
+    pub async fn find(&self, id: &ProblemId) -> Result<Option<Problem>, CodeforcesError> {
+        let problems = self.problems().await?;
+
+        Ok(problems.iter().find(|problem| id.matches(problem)).cloned())
+    }
+
+    // Whether the snapshot is missing or older than the TTL
+    pub async fn is_stale(&self) -> bool {
+        match self.snapshot.read().await.as_ref() {
+            Some(snapshot) => is_expired(snapshot.fetched_at, Utc::now(), self.ttl),
+            None => true,
+        }
+    }
+
+    // =====================
+    //   Cache Refresh
+    // =====================
+
+    // Warm the in-memory cache from `cf_problems`, returning the number of problems loaded
+    pub async fn load_from_db(&self) -> Result<usize, sqlx::Error> {
+        let _guard = self.refresh_lock.lock().await;
+
+        match self.load_snapshot().await? {
+            Some(snapshot) => {
+                let count = snapshot.problems.len();
+                *self.snapshot.write().await = Some(snapshot);
+                Ok(count)
+            }
+            None => Ok(0),
+        }
+    }
+
+    // Download a fresh problemset, returning the number of problems cached.
+    // On failure the previous snapshot keeps being served.
+    pub async fn refresh(&self) -> Result<usize, CodeforcesError> {
+        let _guard = self.refresh_lock.lock().await;
+        Ok(self.download().await?.len())
+    }
+
+    // Fetch from the API, persist and swap the snapshot (caller holds refresh_lock)
+    async fn download(&self) -> Result<Arc<Vec<Problem>>, CodeforcesError> {
+        let problemset = self.client.get_problems(&ProblemFilter::default()).await?;
+        let problems = Arc::new(problemset.problems);
+        let fetched_at = Utc::now();
+
+        // A failed write only costs us the next restart, so keep serving from memory
+        if let Err(e) = self.persist(&problems, fetched_at).await {
+            eprintln!("Error persisting problems to database: {}", e);
+        }
+
+        *self.snapshot.write().await = Some(Snapshot {
+            problems: Arc::clone(&problems),
+            fetched_at,
+        });
+
+        Ok(problems)
+    }
+
+    // =====================
+    //   Database Persistence
+    // =====================
+
+    // Read the persisted problemset, if any
+    async fn load_snapshot(&self) -> Result<Option<Snapshot>, sqlx::Error> {
+        let rows = sqlx::query(
+            "SELECT contest_id, problem_index, name, problem_type, points, rating, tags,
+                solved_count, fetched_at
+            FROM cf_problems
+            ORDER BY contest_id DESC, problem_index",
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut fetched_at = DateTime::<Utc>::MIN_UTC;
+        let mut problems = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            fetched_at = fetched_at.max(row.try_get::<DateTime<Utc>, _>("fetched_at")?);
+            problems.push(Problem {
+                contest_id: Some(row.try_get::<i32, _>("contest_id")? as u32),
+                problemset_name: None,
+                index: row.try_get("problem_index")?,
+                name: row.try_get("name")?,
+                problem_type: row.try_get("problem_type")?,
+                points: row.try_get("points")?,
+                rating: row
+                    .try_get::<Option<i32>, _>("rating")?
+                    .map(|rating| rating as u32),
+                tags: row.try_get("tags")?,
+                solved_count: row
+                    .try_get::<Option<i32>, _>("solved_count")?
+                    .map(|count| count as u32),
+            });
+        }
+
+        Ok(Some(Snapshot {
+            problems: Arc::new(problems),
+            fetched_at,
+        }))
+    }
+
+    // Replace the contents of `cf_problems` in a single transaction
+    async fn persist(
+        &self,
+        problems: &[Problem],
+        fetched_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.db.begin().await?;
+
+        sqlx::query("DELETE FROM cf_problems")
+            .execute(&mut *tx)
+            .await?;
+
+        // Problems outside a contest cannot be linked to, so they are not stored
+        let storable: Vec<&Problem> = problems.iter().filter(|p| p.contest_id.is_some()).collect();
+
+        for chunk in storable.chunks(INSERT_CHUNK_SIZE) {
+            let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+                "INSERT INTO cf_problems
+                (contest_id, problem_index, name, problem_type, points, rating, tags,
+                solved_count, fetched_at) ",
+            );
+
+            builder.push_values(chunk, |mut row, problem| {
+                row.push_bind(problem.contest_id.map(|id| id as i32))
+                    .push_bind(&problem.index)
+                    .push_bind(&problem.name)
+                    .push_bind(&problem.problem_type)
+                    .push_bind(problem.points)
+                    .push_bind(problem.rating.map(|rating| rating as i32))
+                    .push_bind(&problem.tags)
+                    .push_bind(problem.solved_count.map(|count| count as i32))
+                    .push_bind(fetched_at);
+            });
+
+            // The problemset occasionally lists the same problem twice
+            builder.push(" ON CONFLICT (contest_id, problem_index) DO NOTHING");
+            builder.build().execute(&mut *tx).await?;
+        }
+
+        tx.commit().await
+    }
+}
+
+// Whether a snapshot fetched at `fetched_at` is at least `ttl` old at `now`
+// (a fetch time in the future, after a clock change, counts as brand new)
+fn is_expired(fetched_at: DateTime<Utc>, now: DateTime<Utc>, ttl: Duration) -> bool {
+    let age = now
+        .signed_duration_since(fetched_at)
+        .to_std()
+        .unwrap_or_default();
+    age >= ttl
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::{mock_config, problem};
+    use sqlx::postgres::PgPoolOptions;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+    // Cache talking to the mock server, with a database that is never reachable
+    fn test_cache(server: &MockServer) -> ProblemCache {
+        let db = PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://bot@127.0.0.1:1/bot")
+            .unwrap();
+        let client = CodeforcesClient::new(mock_config(server.uri())).unwrap();
+
+        ProblemCache::new(client, db, TTL)
+    }
+
+    async fn set_snapshot(cache: &ProblemCache, problems: Vec<Problem>, fetched_at: DateTime<Utc>) {
+        *cache.snapshot.write().await = Some(Snapshot {
+            problems: Arc::new(problems),
+            fetched_at,
+        });
+    }
+
+    #[test]
+    fn snapshots_expire_once_the_ttl_has_passed() {
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(TTL).unwrap();
+
+        assert!(!is_expired(now, now, TTL));
+        assert!(!is_expired(
+            now - ttl + chrono::Duration::seconds(1),
+            now,
+            TTL
+        ));
+        assert!(is_expired(now - ttl, now, TTL));
+        assert!(is_expired(now - ttl * 2, now, TTL));
+        assert!(!is_expired(now + chrono::Duration::hours(1), now, TTL));
+        assert!(is_expired(now, now, Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn only_missing_or_old_snapshots_are_stale() {
+        let server = MockServer::start().await;
+        let cache = test_cache(&server);
+        assert!(cache.is_stale().await);
+
+        set_snapshot(&cache, vec![problem(4, "A")], Utc::now()).await;
+        assert!(!cache.is_stale().await);
+
+        set_snapshot(
+            &cache,
+            vec![problem(4, "A")],
+            Utc::now() - chrono::Duration::hours(7),
+        )
+        .await;
+        assert!(cache.is_stale().await);
+    }
+
+    #[tokio::test]
+    async fn filters_the_loaded_snapshot_without_fetching_again() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/problemset.problems"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let cache = test_cache(&server);
+        let problems = vec![
+            Problem {
+                rating: Some(1500),
+                ..problem(1999, "D")
+            },
+            Problem {
+                rating: None,
+                ..problem(1999, "E")
+            },
+            problem(4, "A"),
+        ];
+        set_snapshot(&cache, problems, Utc::now()).await;
+
+        let matching = cache
+            .problems_matching(&ProblemFilter::rating_range(Some(1000), Some(2000)))
+            .await
+            .unwrap();
+        let ids: Vec<String> = matching
+            .iter()
+            .filter_map(|problem| problem.id())
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(ids, ["1999D"]);
+
+        let found = cache.find(&"4A".parse().unwrap()).await.unwrap();
+        assert_eq!(found.map(|problem| problem.index), Some("A".to_string()));
+        assert!(cache.find(&"4B".parse().unwrap()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn refresh_swaps_in_the_downloaded_problemset() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/problemset.problems"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                include_str!("../../tests/fixtures/codeforces/problemset_problems.json"),
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let cache = test_cache(&server);
+        set_snapshot(
+            &cache,
+            vec![problem(4, "A")],
+            Utc::now() - chrono::Duration::hours(7),
+        )
+        .await;
+
+        // Persisting fails without a database, but the new snapshot is still served
+        assert_eq!(cache.refresh().await.unwrap(), 4);
+        assert!(!cache.is_stale().await);
+
+        let problems = cache.problems().await.unwrap();
+        assert_eq!(problems.len(), 4);
+        assert!(cache.find(&"4A".parse().unwrap()).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,222 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::api::codeforces::Problem;
+use std::fmt;
+use std::str::FromStr;
+
+// =====================
+//   Problem Identifier
+// =====================
+
+// Contest ids from this value upwards belong to the gym
+const GYM_CONTEST_ID_START: u32 = 100_000;
+
+// Canonical identifier of a Codeforces problem: contest id + uppercase index.
+// Displays as `467B`, `1850A1` or `100001A`, which is also how it is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProblemId {
+    pub contest_id: u32,
+    pub index: String,
+}
+
+// Returned when a string is not a recognizable problem identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProblemIdError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseProblemIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Codeforces problem id: {}", self.input)
+    }
+}
+
+impl std::error::Error for ParseProblemIdError {}
+
+impl ProblemId {
+    // Build an id from its parts, normalizing the index (e.g. `a1` -> `A1`)
+    pub fn new(contest_id: u32, index: &str) -> Option<Self> {
+        let index = index.trim().to_ascii_uppercase();
+        let mut chars = index.chars();
+
+        // Index: one letter, optionally followed by up to two digits (A, A1, B12)
+        let valid = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.clone().count() <= 2
+            && chars.all(|c| c.is_ascii_digit());
+
+        (contest_id > 0 && valid).then_some(Self { contest_id, index })
+    }
+
+    // Whether the problem belongs to a gym contest
+    pub fn is_gym(&self) -> bool {
+        self.contest_id >= GYM_CONTEST_ID_START
+    }
+
+    // Link to the problem statement
+    pub fn url(&self) -> String {
+        if self.is_gym() {
+            format!(
+                "https://codeforces.com/gym/{}/problem/{}",
+                self.contest_id, self.index
+            )
+        } else {
+            format!(
+                "https://codeforces.com/problemset/problem/{}/{}",
+                self.contest_id, self.index
+            )
+        }
+    }
+
+    // Whether an API problem is this one
+    pub fn matches(&self, problem: &Problem) -> bool {
+        problem.contest_id == Some(self.contest_id)
+            && problem.index.eq_ignore_ascii_case(&self.index)
+    }
+
+    // Parse `467B`, `CF467B` or `467 B`
+    fn parse_short(input: &str) -> Option<Self> {
+        let input = input
+            .strip_prefix("CF")
+            .or_else(|| input.strip_prefix("cf"))
+            .or_else(|| input.strip_prefix("Cf"))
+            .unwrap_or(input)
+            .trim_start();
+
+        let split_point = input.find(|c: char| !c.is_ascii_digit())?;
+        let contest_id = input[..split_point].parse().ok()?;
+        let index = input[split_point..].trim_start_matches([' ', '-', '/', '_']);
+
+        Self::new(contest_id, index)
+    }
+
+    // Parse a problemset, contest or gym URL
+    fn parse_url(input: &str) -> Option<Self> {
+        let without_scheme = input
+            .strip_prefix("https://")
+            .or_else(|| input.strip_prefix("http://"))
+            .unwrap_or(input);
+
+        // Drop query string and fragment
+        let without_query = without_scheme.split(['?', '#']).next()?;
+
+        let mut segments = without_query.split('/').filter(|s| !s.is_empty());
+        let host = segments.next()?.to_ascii_lowercase();
+        if host != "codeforces.com" && !host.ends_with(".codeforces.com") {
+            return None;
+        }
+
+        let segments: Vec<&str> = segments.collect();
+        let (contest, index) = match segments.as_slice() {
+            // codeforces.com/problemset/problem/467/B
+            ["problemset", "problem", contest, index] => (contest, index),
+            // codeforces.com/problemset/gymProblem/100001/A
+            ["problemset", "gymProblem", contest, index] => (contest, index),
+            // codeforces.com/contest/467/problem/B and codeforces.com/gym/100001/problem/A
+            ["contest" | "gym", contest, "problem", index] => (contest, index),
+            _ => return None,
+        };
+
+        Self::new(contest.parse().ok()?, index)
+    }
+}
+
+impl FromStr for ProblemId {
+    type Err = ParseProblemIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim().trim_start_matches('<').trim_end_matches('>');
+
+        let parsed = if input.contains("codeforces.com") {
+            Self::parse_url(input)
+        } else {
+            Self::parse_short(input)
+        };
+
+        parsed.ok_or_else(|| ParseProblemIdError {
+            input: s.trim().to_string(),
+        })
+    }
+}
+
+impl fmt::Display for ProblemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.contest_id, self.index)
+    }
+}
+
+impl Problem {
+    // Canonical identifier, for problems that belong to a contest
+    pub fn id(&self) -> Option<ProblemId> {
+        ProblemId::new(self.contest_id?, &self.index)
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> String {
+        input.parse::<ProblemId>().unwrap().to_string()
+    }
+
+    #[test]
+    fn parses_short_forms() {
+        assert_eq!(parse("467B"), "467B");
+        assert_eq!(parse("467b"), "467B");
+        assert_eq!(parse("CF467B"), "467B");
+        assert_eq!(parse("cf 467 b"), "467B");
+        assert_eq!(parse("1850A1"), "1850A1");
+        assert_eq!(parse("1a2"), "1A2");
+        assert_eq!(parse("100001A"), "100001A");
+    }
+
+    #[test]
+    fn parses_urls() {
+        assert_eq!(
+            parse("https://codeforces.com/problemset/problem/467/B"),
+            "467B"
+        );
+        assert_eq!(
+            parse("codeforces.com/contest/1850/problem/a1?locale=en"),
+            "1850A1"
+        );
+        assert_eq!(
+            parse("<https://codeforces.com/gym/100001/problem/A>"),
+            "100001A"
+        );
+        assert_eq!(
+            parse("https://m1.codeforces.com/problemset/gymProblem/100001/C"),
+            "100001C"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        for input in [
+            "",
+            "B467",
+            "467",
+            "467BB",
+            "467B123",
+            "https://example.com/contest/1/problem/A",
+        ] {
+            assert!(
+                input.parse::<ProblemId>().is_err(),
+                "{input} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn gym_problems_link_to_the_gym() {
+        let id: ProblemId = "100001A".parse().unwrap();
+        assert!(id.is_gym());
+        assert_eq!(id.url(), "https://codeforces.com/gym/100001/problem/A");
+    }
+}
@@ -0,0 +1,137 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+// =====================
+//   Token Bucket Rate Limiter
+// =====================
+
+// Shared token bucket - every caller must acquire a token before hitting the API.
+// The bucket holds up to `capacity` tokens and regains one every `refill_interval`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_interval: Duration,
+    bucket: Mutex<Bucket>,
+}
+
+// Mutable bucket state, guarded by the limiter's mutex
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    // Create a limiter that starts full
+    pub fn new(capacity: u32, refill_interval: Duration) -> Self {
+        let capacity = f64::from(capacity.max(1));
+
+        Self {
+            capacity,
+            refill_interval,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    // Wait until a token is available and consume it
+    pub async fn acquire(&self) {
+        loop {
+            // Compute the wait in a separate scope so the lock is never held across an await
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+                self.refill(&mut bucket);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+
+                self.refill_interval.mul_f64(1.0 - bucket.tokens)
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    // Add the tokens earned since the last refill, capped at capacity
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+
+        if self.refill_interval.is_zero() {
+            bucket.tokens = self.capacity;
+        } else {
+            let elapsed = now.duration_since(bucket.last_refill);
+            let earned = elapsed.as_secs_f64() / self.refill_interval.as_secs_f64();
+            bucket.tokens = (bucket.tokens + earned).min(self.capacity);
+        }
+
+        bucket.last_refill = now;
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Time since `start` on the paused test clock
+    fn elapsed_since(start: Instant) -> Duration {
+        Instant::now().duration_since(start)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn spaces_calls_once_the_burst_is_spent() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(elapsed_since(start), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(elapsed_since(start), Duration::from_secs(1));
+
+        limiter.acquire().await;
+        assert_eq!(elapsed_since(start), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refills_up_to_capacity_while_idle() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(1));
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        // Idle long enough for five tokens, but the bucket only holds two
+        tokio::time::advance(Duration::from_secs(5)).await;
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(elapsed_since(start), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(elapsed_since(start), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn zero_interval_never_waits() {
+        let limiter = RateLimiter::new(1, Duration::ZERO);
+        let start = Instant::now();
+
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+        assert_eq!(elapsed_since(start), Duration::ZERO);
+    }
+}
@@ -0,0 +1,156 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{CommandContext, Reply};
+use crate::tasks::daily::{
+    DEFAULT_DAILY_BONUS, DEFAULT_DAILY_HOUR, DEFAULT_DAILY_MINUTE, DEFAULT_MAX_RATING,
+    DEFAULT_MIN_RATING,
+};
+use serenity::builder::{CreateCommand, CreateEmbed};
+use sqlx::Row;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /config slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("config")
+}
+
+// =====================
+//   Config Command Implementation
+// =====================
+
+// Shows the guild's whole bot configuration (`!config` and `/config`)
+pub async fn execute(ctx: &CommandContext<'_>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Query Guild Configuration
+    // =====================
+
+    let row = match sqlx::query(
+        "SELECT daily_channel_id, daily_hour, daily_minute,
+            min_rating, max_rating, admin_role_id, solve_channel_id,
+            sync_awards_coins, daily_bonus, daily_solved_threshold
+        FROM guild_config
+        WHERE guild_id = $1",
+    )
+    .bind(guild_id.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(Some(row)) => row,
+        Ok(None) => return Reply::text(ctx.tr("config.not_configured")),
+        Err(e) => {
+            eprintln!("Database error in config command: {}", e);
+            return Reply::text(ctx.tr("config.read_error"));
+        }
+    };
+
+    // Unset columns fall back to the values the bot actually uses
+    let prefix = &ctx.settings.prefix;
+    let daily_channel = row
+        .try_get::<Option<i64>, _>("daily_channel_id")
+        .unwrap_or_default();
+    let daily_hour = row
+        .try_get::<Option<i32>, _>("daily_hour")
+        .unwrap_or_default()
+        .unwrap_or(DEFAULT_DAILY_HOUR);
+    let daily_minute = row
+        .try_get::<Option<i32>, _>("daily_minute")
+        .unwrap_or_default()
+        .unwrap_or(DEFAULT_DAILY_MINUTE);
+    let min_rating = row
+        .try_get::<Option<i32>, _>("min_rating")
+        .unwrap_or_default()
+        .unwrap_or(DEFAULT_MIN_RATING);
+    let max_rating = row
+        .try_get::<Option<i32>, _>("max_rating")
+        .unwrap_or_default()
+        .unwrap_or(DEFAULT_MAX_RATING);
+    let admin_role = row
+        .try_get::<Option<i64>, _>("admin_role_id")
+        .unwrap_or_default();
+    let solve_channel = row
+        .try_get::<Option<i64>, _>("solve_channel_id")
+        .unwrap_or_default();
+    let daily_bonus = row
+        .try_get::<Option<i32>, _>("daily_bonus")
+        .unwrap_or_default()
+        .unwrap_or(DEFAULT_DAILY_BONUS);
+    let solved_threshold = row
+        .try_get::<Option<i32>, _>("daily_solved_threshold")
+        .unwrap_or_default();
+    let sync_awards_coins = row
+        .try_get::<Option<bool>, _>("sync_awards_coins")
+        .unwrap_or_default()
+        .unwrap_or(false);
+
+    // =====================
+    //   Discord Embed Creation
+    // =====================
+
+    let channel_text = match daily_channel {
+        Some(channel_id) => format!("<#{}>", channel_id),
+        None => ctx.tr_args("config.channel_unset", &[("prefix", prefix)]),
+    };
+    let solve_channel_text = match solve_channel {
+        Some(channel_id) => format!("<#{}>", channel_id),
+        None => ctx.tr_args("config.solve_channel_unset", &[("prefix", prefix)]),
+    };
+    let solved_threshold_text = match solved_threshold {
+        Some(percent) => ctx.tr_args("config.solved_threshold_value", &[("percent", &percent)]),
+        None => ctx.tr("config.solved_threshold_off").to_string(),
+    };
+    let admin_text = match admin_role {
+        Some(role_id) => format!("<@&{}>", role_id),
+        None => ctx.tr("config.admin_unset").to_string(),
+    };
+
+    let embed = CreateEmbed::new()
+        .title(ctx.tr("config.title"))
+        .field(ctx.tr("config.prefix"), format!("`{}`", prefix), true)
+        .field(ctx.tr("config.language"), ctx.language().name(), true)
+        .field(ctx.tr("config.admin_role"), admin_text, true)
+        .field(ctx.tr("config.channel"), channel_text, false)
+        .field(
+            ctx.tr("config.time"),
+            ctx.tr_args(
+                "config.time_value",
+                &[("time", &format!("{:02}:{:02}", daily_hour, daily_minute))],
+            ),
+            true,
+        )
+        .field(
+            ctx.tr("config.rating"),
+            format!("{} - {}", min_rating, max_rating),
+            true,
+        )
+        .field(
+            ctx.tr("config.solved_threshold"),
+            solved_threshold_text,
+            true,
+        )
+        .field(
+            ctx.tr("config.daily_bonus"),
+            ctx.tr_args("config.daily_bonus_value", &[("coins", &daily_bonus)]),
+            true,
+        )
+        .field(ctx.tr("config.solve_channel"), solve_channel_text, false)
+        .field(
+            ctx.tr("config.sync_reward"),
+            if sync_awards_coins {
+                ctx.tr("config.sync_reward_on")
+            } else {
+                ctx.tr("config.sync_reward_off")
+            },
+            true,
+        );
+
+    Reply::embed(embed)
+}
@@ -0,0 +1,8 @@
+// =====================
+//   Admin Commands Module
+// =====================
+
+// Module declarations for server administration commands
+pub mod config; // Command to show the server's bot configuration
+pub mod setadminrole; // Command to choose the role allowed to configure the bot
+pub mod setlanguage; // Command to choose the language of the bot's replies
@@ -0,0 +1,101 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
+use serenity::model::id::RoleId;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setadminrole slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setadminrole").add_option(CreateCommandOption::new(
+        CommandOptionType::Role,
+        "role",
+        "Role allowed to configure the bot (leave empty to remove it)",
+    ))
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setadminrole @role` and `!setadminrole none`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    if args.eq_ignore_ascii_case("none") {
+        return run(ctx, None).await;
+    }
+
+    // Validate mention format: <@&ID> and extract role ID
+    let Some(id_str) = args.strip_prefix("<@&").and_then(|s| s.strip_suffix('>')) else {
+        return Reply::text(
+            ctx.tr_args("setadminrole.incorrect_format", &[("usage", &ctx.usage())]),
+        );
+    };
+
+    match id_str.parse::<u64>() {
+        Ok(id) if id != 0 => run(ctx, Some(RoleId::new(id))).await,
+        _ => Reply::text(ctx.tr("setadminrole.invalid_role")),
+    }
+}
+
+// Handle `/setadminrole [role]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let role_id = options.iter().find_map(|option| match option.value {
+        ResolvedValue::Role(role) if option.name == "role" => Some(role.id),
+        _ => None,
+    });
+
+    run(ctx, role_id).await
+}
+
+// =====================
+//   Set Admin Role Command Implementation
+// =====================
+
+// Stores (or clears) the bot admin role of the guild
+async fn run(ctx: &CommandContext<'_>, role_id: Option<RoleId>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET admin_role_id = $1
+        WHERE guild_id = $2",
+    )
+    .bind(role_id.map(|id| id.get() as i64))
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) => {
+            // Permission checks read the cached settings
+            ctx.bot.settings.invalidate(guild_id).await;
+
+            match role_id {
+                Some(role_id) => {
+                    Reply::text(ctx.tr_args("setadminrole.saved", &[("role", &role_id)]))
+                }
+                None => Reply::text(ctx.tr("setadminrole.removed")),
+            }
+        }
+        Err(e) => {
+            eprintln!("Error saving admin role: {}", e);
+            Reply::text(ctx.tr("setadminrole.save_error"))
+        }
+    }
+}
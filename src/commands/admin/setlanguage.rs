@@ -0,0 +1,114 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{string_option, CommandContext, Reply};
+use crate::i18n::{tr_args, Language};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setlanguage slash command
+pub fn register() -> CreateCommand {
+    let option = Language::ALL.into_iter().fold(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "language",
+            "Language of the bot's replies (leave empty to view)",
+        ),
+        |option, language| option.add_string_choice(language.name(), language.code()),
+    );
+
+    CreateCommand::new("setlanguage").add_option(option)
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setlanguage` (view) and `!setlanguage es` (set)
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    run(ctx, args).await
+}
+
+// Handle `/setlanguage [language]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(ctx, string_option(options, "language").unwrap_or_default()).await
+}
+
+// Supported languages as typed by users (`en` (English), `es` (Español))
+fn available_languages() -> String {
+    Language::ALL
+        .iter()
+        .map(|language| format!("`{}` ({})", language.code(), language.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// =====================
+//   Set Language Command Implementation
+// =====================
+
+// Shows or changes the language the bot replies in for this guild
+async fn run(ctx: &CommandContext<'_>, code: &str) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    if code.is_empty() {
+        return Reply::text(ctx.tr_args(
+            "setlanguage.current",
+            &[
+                ("language", &ctx.language().name()),
+                ("usage", &ctx.usage()),
+                ("languages", &available_languages()),
+            ],
+        ));
+    }
+
+    let Some(language) = Language::from_code(code) else {
+        return Reply::text(ctx.tr_args(
+            "setlanguage.unknown",
+            &[("language", &code), ("languages", &available_languages())],
+        ));
+    };
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET language = $1
+        WHERE guild_id = $2",
+    )
+    .bind(language.code())
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) => {
+            // Replies read the language from the cached settings
+            ctx.bot.settings.invalidate(guild_id).await;
+
+            // Confirm in the new language
+            Reply::text(tr_args(
+                language,
+                "setlanguage.saved",
+                &[("language", &language.name())],
+            ))
+        }
+        Err(e) => {
+            eprintln!("Error saving language: {}", e);
+            Reply::text(ctx.tr("setlanguage.save_error"))
+        }
+    }
+}
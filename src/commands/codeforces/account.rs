@@ -2,42 +2,59 @@
 //   Imports and Dependencies
 // =====================
 
-use crate::api;
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use serenity::model::guild::PartialGuild;
+use super::{api_error_message, sync, verification};
+use crate::api::codeforces::CodeforcesError;
+use crate::commands::context::{string_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use serenity::model::id::{GuildId, UserId};
 use sqlx::PgPool;
-use std::sync::Arc;
 
 // =====================
-//   Account Command Implementation
+//   Slash Command Registration
 // =====================
 
-// Main function to handle the !account command
-// Links the user's Codeforces handle to their Discord ID and saves profile info
-pub async fn execute(
-    http: &Arc<Http>,
-    msg: &Message,
-    db: &PgPool,
-    guild: &PartialGuild,
-) -> Result<(), serenity::Error> {
-    // =====================
-    //   Extract Codeforces Handle
-    // =====================
+// Definition of the /account slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("account").add_option(
+        CreateCommandOption::new(CommandOptionType::String, "handle", "Codeforces handle")
+            .max_length(24)
+            .required(true),
+    )
+}
 
-    // Extract Codeforces handle from message content after "!account "
-    let handle = msg.content["!account ".len()..].trim();
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!account handle`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    run(ctx, args).await
+}
+
+// Handle `/account handle`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(
+        ctx,
+        string_option(options, "handle").unwrap_or_default().trim(),
+    )
+    .await
+}
+
+// =====================
+//   Account Command Implementation
+// =====================
+
+// Links the user's Codeforces handle to their Discord ID and saves profile info,
+// once they proved they own the handle
+async fn run(ctx: &CommandContext<'_>, handle: &str) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
 
     // Check if handle was provided
     if handle.is_empty() {
-        let _ = msg
-            .channel_id
-            .say(
-                http,
-                "❌ Por favor proporciona un handle de Codeforces. Uso: `!account tu_handle`",
-            )
-            .await;
-        return Ok(());
+        return Reply::text(ctx.tr_args("account.missing_handle", &[("usage", &ctx.usage())]));
     }
 
     // =====================
@@ -45,93 +62,235 @@ pub async fn execute(
     // =====================
 
     // Fetch user information from Codeforces API
-    match api::codeforces::get_user_info(handle).await {
-        Ok(user_info) => {
-            // Check if API returned successful response
-            if user_info.status != "OK" || user_info.result.is_empty() {
-                let _ = msg.channel_id.say(http,
-                    &format!("❌ No se encontró el usuario `{}` en Codeforces. Verifica que el handle sea correcto.", handle)
-                ).await;
-                return Ok(());
-            }
-
-            // Get the first (and should be only) user from results
-            let user_data = &user_info.result[0];
-
+    let user_data = match ctx.bot.codeforces.get_user_info(handle).await {
+        Ok(user_data) => user_data,
+        // =====================
+        //   Handle Not Found Response
+        // =====================
+        Err(CodeforcesError::NotFound { .. }) => {
+            return Reply::text(ctx.tr_args("account.not_found", &[("handle", &handle)]));
+        }
+        Err(e) => {
             // =====================
-            //   Database Update
+            //   API Error Response
             // =====================
 
-            // Extract relevant information with defaults
-            let codeforces_handle = user_data.handle.as_deref().unwrap_or(handle);
-            let codeforces_rating = user_data.rating.unwrap_or(0);
-            let codeforces_rank = user_data.rank.as_deref().unwrap_or("unrated");
-
-            // Insert or update user information in database
-            match sqlx::query(
-                "INSERT INTO user_info (guild_id, user_id, codeforces_handle, codeforces_rating, codeforces_rank)
-                VALUES ($1, $2, $3, $4, $5)
-                ON CONFLICT (guild_id, user_id)
-                DO UPDATE SET
-                    codeforces_handle = EXCLUDED.codeforces_handle,
-                    codeforces_rating = EXCLUDED.codeforces_rating,
-                    codeforces_rank = EXCLUDED.codeforces_rank"
-            )
-            .bind(guild.id.get() as i64)
-            .bind(msg.author.id.get() as i64)
-            .bind(codeforces_handle)
-            .bind(codeforces_rating)
-            .bind(codeforces_rank)
-            .execute(db)
+            eprintln!("Codeforces API error: {}", e);
+            return Reply::text(api_error_message(ctx.language(), &e));
+        }
+    };
+
+    // Extract relevant information with defaults
+    let codeforces_handle = user_data.handle.as_deref().unwrap_or(handle);
+    let codeforces_rating = user_data.rating.unwrap_or(0);
+    let codeforces_rank = user_data.rank.as_deref().unwrap_or("unrated");
+    let codeforces_max_rating = user_data.max_rating.unwrap_or(codeforces_rating);
+
+    // =====================
+    //   Ownership Verification
+    // =====================
+
+    // Running the command again with the handle already linked only refreshes the profile
+    let linked_handle = match sqlx::query_scalar::<_, Option<String>>(
+        "SELECT codeforces_handle FROM user_info WHERE guild_id = $1 AND user_id = $2",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(handle) => handle.flatten(),
+        Err(e) => {
+            eprintln!("Database error reading linked handle: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    let already_linked =
+        linked_handle.is_some_and(|linked| linked.eq_ignore_ascii_case(codeforces_handle));
+    if !already_linked {
+        if let Err(reply) = verify_ownership(ctx, guild_id, codeforces_handle).await {
+            return reply;
+        }
+    }
+
+    // =====================
+    //   Database Update
+    // =====================
+
+    if let Err(e) = save_link(
+        &ctx.bot.db,
+        guild_id,
+        ctx.author.id,
+        codeforces_handle,
+        codeforces_rating,
+        codeforces_rank,
+        codeforces_max_rating,
+    )
+    .await
+    {
+        // =====================
+        //   Database Error Response
+        // =====================
+
+        eprintln!("Database error in account command: {}", e);
+        return Reply::text(ctx.tr("account.save_error"));
+    }
+
+    // =====================
+    //   Success Response
+    // =====================
+
+    // Confirmation message with user info
+    let linked = ctx.tr_args(
+        "account.linked",
+        &[
+            ("handle", &codeforces_handle),
+            ("rating", &codeforces_rating),
+            ("rank", &codeforces_rank),
+            ("max_rating", &codeforces_max_rating),
+        ],
+    );
+
+    // A newly linked handle brings its past solves along
+    if already_linked {
+        return Reply::text(linked);
+    }
+
+    let imported = sync::import_message(ctx, guild_id, codeforces_handle).await;
+    Reply::text(format!("{}\n\n{}", linked, imported))
+}
+
+// Check the member's pending verification of a handle, starting one when there is none.
+// Returns the reply to send while the handle isn't proven yet.
+async fn verify_ownership(
+    ctx: &CommandContext<'_>,
+    guild_id: GuildId,
+    handle: &str,
+) -> Result<(), Reply> {
+    let db = &ctx.bot.db;
+    let command = format!("{}account {}", ctx.settings.prefix, handle);
+
+    let pending = match verification::pending(db, guild_id, ctx.author.id).await {
+        Ok(pending) => pending,
+        Err(e) => {
+            eprintln!("Database error reading pending verification: {}", e);
+            return Err(Reply::text(ctx.tr("common.database_error")));
+        }
+    };
+
+    // =====================
+    //   Check Pending Verification
+    // =====================
+
+    if let Some(pending) = pending.filter(|pending| pending.handle.eq_ignore_ascii_case(handle)) {
+        let submissions = match ctx
+            .bot
+            .codeforces
+            .get_user_status(handle, None, Some(verification::RECENT_SUBMISSIONS))
             .await
-            {
-                Ok(_) => {
-                    // =====================
-                    //   Success Response
-                    // =====================
-
-                    // Send confirmation message with user info
-                    let response = format!(
-                        "✅ **Cuenta de Codeforces vinculada exitosamente!**\n\n\
-                        👤 **Handle:** `{}`\n\
-                        🏆 **Rating:** `{}`\n\
-                        🎯 **Rank:** `{}`\n\
-                        📊 **Rating Máximo:** `{}`",
-                        codeforces_handle,
-                        codeforces_rating,
-                        codeforces_rank,
-                        user_data.maxRating.unwrap_or(codeforces_rating)
-                    );
-
-                    let _ = msg.channel_id.say(http, &response).await;
-                }
-                Err(e) => {
-                    // =====================
-                    //   Database Error Response
-                    // =====================
-
-                    eprintln!("Database error in account command: {}", e);
-                    let _ = msg.channel_id.say(http,
-                        "❌ Error al guardar la información en la base de datos. Intenta de nuevo más tarde."
-                    ).await;
-                }
+        {
+            Ok(submissions) => submissions,
+            Err(e) => {
+                eprintln!("Codeforces API error verifying handle: {}", e);
+                return Err(Reply::text(api_error_message(ctx.language(), &e)));
             }
+        };
+
+        if pending.is_proven_by(&submissions) {
+            return Ok(());
         }
+
+        return Err(Reply::text(ctx.tr_args(
+            "account.verification_pending",
+            &[
+                ("handle", &handle),
+                ("problem", &pending.problem_id),
+                ("url", &pending.problem_id.url()),
+                ("command", &command),
+                ("expires", &pending.expires_at.timestamp()),
+            ],
+        )));
+    }
+
+    // =====================
+    //   Start New Verification
+    // =====================
+
+    let problem_id = match ctx.bot.problem_cache.problems().await {
+        Ok(problems) => verification::pick_problem(&problems),
         Err(e) => {
-            // =====================
-            //   API Error Response
-            // =====================
+            eprintln!("Error loading problems for verification: {}", e);
+            None
+        }
+    };
+    let Some(problem_id) = problem_id else {
+        return Err(Reply::text(ctx.tr("account.verification_unavailable")));
+    };
 
-            eprintln!("Codeforces API error: {}", e);
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ Error al conectar con la API de Codeforces. Intenta de nuevo más tarde.",
-                )
-                .await;
+    match verification::start(db, guild_id, ctx.author.id, handle, &problem_id).await {
+        Ok(pending) => Err(Reply::text(ctx.tr_args(
+            "account.verification_started",
+            &[
+                ("handle", &handle),
+                ("problem", &pending.problem_id),
+                ("url", &pending.problem_id.url()),
+                ("command", &command),
+                ("minutes", &verification::VERIFICATION_MINUTES),
+                ("expires", &pending.expires_at.timestamp()),
+            ],
+        ))),
+        Err(e) => {
+            eprintln!("Database error starting verification: {}", e);
+            Err(Reply::text(ctx.tr("common.database_error")))
         }
     }
+}
+
+// Store the linked handle and its profile, ending the member's verification
+async fn save_link(
+    db: &PgPool,
+    guild_id: GuildId,
+    user_id: UserId,
+    handle: &str,
+    rating: i32,
+    rank: &str,
+    max_rating: i32,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // Insert or update user information in database.
+    // The link date only changes when a different handle is linked.
+    sqlx::query(
+        "INSERT INTO user_info (guild_id, user_id, codeforces_handle, codeforces_rating, codeforces_rank, codeforces_max_rating, linked_at)
+        VALUES ($1, $2, $3, $4, $5, $6, NOW())
+        ON CONFLICT (guild_id, user_id)
+        DO UPDATE SET
+            codeforces_handle = EXCLUDED.codeforces_handle,
+            codeforces_rating = EXCLUDED.codeforces_rating,
+            codeforces_rank = EXCLUDED.codeforces_rank,
+            codeforces_max_rating = EXCLUDED.codeforces_max_rating,
+            linked_at = CASE
+                WHEN user_info.codeforces_handle IS DISTINCT FROM EXCLUDED.codeforces_handle
+                    OR user_info.linked_at IS NULL
+                THEN EXCLUDED.linked_at
+                ELSE user_info.linked_at
+            END",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(user_id.get() as i64)
+    .bind(handle)
+    .bind(rating)
+    .bind(rank)
+    .bind(max_rating)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM pending_verification WHERE guild_id = $1 AND user_id = $2")
+        .bind(guild_id.get() as i64)
+        .bind(user_id.get() as i64)
+        .execute(&mut *tx)
+        .await?;
 
-    Ok(())
+    tx.commit().await
 }
@@ -0,0 +1,65 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use super::api_error_message;
+use crate::commands::context::{CommandContext, Reply};
+use crate::tasks::daily::{current_daily, daily_embed};
+use serenity::builder::CreateCommand;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /daily slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("daily")
+}
+
+// =====================
+//   Daily Command Implementation
+// =====================
+
+// Shows the guild's current daily problem again (`!daily` and `/daily`)
+pub async fn execute(ctx: &CommandContext<'_>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    let daily = match current_daily(&ctx.bot.db, guild_id.get() as i64).await {
+        Ok(Some(daily)) => daily,
+        Ok(None) => {
+            return Reply::text(ctx.tr_args("daily.none", &[("prefix", &ctx.settings.prefix)]));
+        }
+        Err(e) => {
+            eprintln!("Database error reading daily problem: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    // Link back to the original post, where the discussion happens
+    let post_url = format!(
+        "https://discord.com/channels/{}/{}/{}",
+        guild_id, daily.channel_id, daily.message_id
+    );
+    let content = ctx.tr_args(
+        "daily.current",
+        &[("posted", &daily.posted_at.timestamp()), ("url", &post_url)],
+    );
+
+    // The problem details come from the cached problemset
+    match ctx.bot.problem_cache.find(&daily.problem_id).await {
+        Ok(Some(problem)) => {
+            Reply::embed(daily_embed(ctx.language(), &problem)).with_content(content)
+        }
+        Ok(None) => Reply::text(format!(
+            "{}\n{}",
+            content,
+            ctx.tr_args("problem.solve_link", &[("url", &daily.problem_id.url())])
+        )),
+        Err(e) => {
+            eprintln!("Error loading daily problem from cache: {}", e);
+            Reply::text(api_error_message(ctx.language(), &e))
+        }
+    }
+}
@@ -2,9 +2,134 @@
 //   Codeforces Commands Module
 // =====================
 
+use crate::api::codeforces::{CodeforcesError, Problem};
+use crate::i18n::{tr, tr_args, Language};
+use crate::Bot;
+use serenity::builder::{CreateAutocompleteResponse, CreateCommandOption, CreateEmbed};
+use serenity::model::application::{AutocompleteOption, CommandOptionType};
+
 // Module declarations for Codeforces-related bot commands
 pub mod account; // Command to link Codeforces account to Discord user
+pub mod daily; // Command to show the guild's current daily problem again
 pub mod problem; // Command to fetch random Codeforces problems with difficulty filtering
+pub mod ratinghistory; // Command to show a linked user's Codeforces rating history
 pub mod setchannel; // Command to configure which channel receives daily problems
+pub mod setdailybonus; // Command to set the coins earned by solving the daily problem
 pub mod sethour; // Command to set the time for daily problem posting
+pub mod setrating; // Command to set the rating range of daily problems
+pub mod setsolvechannel; // Command to configure where automatically detected solves are announced
+pub mod setsolvedthreshold; // Command to skip daily problems most members already solved
+pub mod setsyncreward; // Command to choose whether imported historical solves award coins
 pub mod solved; // Command to verify and mark Codeforces problems as solved
+pub mod sync; // Command to import every problem a linked handle has solved
+pub mod verification; // Proof of handle ownership required before linking an account
+
+// =====================
+//   Problem Ratings
+// =====================
+
+// Range of ratings Codeforces assigns to problems (always a multiple of 100)
+pub const MIN_PROBLEM_RATING: u32 = 800;
+pub const MAX_PROBLEM_RATING: u32 = 3500;
+
+// Problem ratings starting with the digits typed so far, for autocomplete
+pub fn rating_suggestions(typed: &str) -> Vec<u32> {
+    let typed = typed.trim();
+
+    (MIN_PROBLEM_RATING..=MAX_PROBLEM_RATING)
+        .step_by(100)
+        .filter(|rating| rating.to_string().starts_with(typed))
+        .take(25)
+        .collect()
+}
+
+// Slash command option for a problem rating, with autocomplete
+pub fn rating_option(name: &str, description: &str) -> CreateCommandOption {
+    CreateCommandOption::new(CommandOptionType::Integer, name, description)
+        .min_int_value(MIN_PROBLEM_RATING.into())
+        .max_int_value(MAX_PROBLEM_RATING.into())
+        .set_autocomplete(true)
+}
+
+// Suggest ratings while the user types a rating option
+pub async fn rating_autocomplete(
+    _bot: &Bot,
+    option: &AutocompleteOption<'_>,
+) -> CreateAutocompleteResponse {
+    rating_suggestions(option.value)
+        .into_iter()
+        .fold(CreateAutocompleteResponse::new(), |response, rating| {
+            response.add_int_choice(rating.to_string(), rating.into())
+        })
+}
+
+// =====================
+//   Problem Embeds
+// =====================
+
+// Embed presenting a problem: name, difficulty bars, tags and link (`!problem` and the daily)
+pub fn problem_embed(language: Language, title: &str, problem: &Problem) -> CreateEmbed {
+    // Create difficulty bars based on rating
+    let rating = problem.rating.unwrap_or(0);
+    let difficulty_bars = match rating {
+        r if r <= 1000 => "🟩".to_string(), // Beginner
+        r if r <= 1400 => "🟩".repeat(2),   // Easy
+        r if r <= 1800 => "🟩".repeat(3),   // Medium
+        r if r <= 2200 => "🟩".repeat(4),   // Hard
+        _ => "🟩".repeat(5),                // Expert
+    };
+
+    // Format tags for display
+    let tags_text = if problem.tags.is_empty() {
+        tr(language, "common.no_tags").to_string()
+    } else {
+        problem
+            .tags
+            .iter()
+            .map(|tag| format!("`{}`", tag))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    // Generate problem URL (gym problems link to the gym)
+    let problem_url = problem
+        .id()
+        .map(|id| id.url())
+        .unwrap_or_else(|| "https://codeforces.com/problemset".to_string());
+
+    CreateEmbed::new()
+        .title(title)
+        .description(format!("**{}**", problem.name))
+        .field(
+            tr(language, "problem.difficulty"),
+            format!("{} **{}**", difficulty_bars, rating),
+            true,
+        )
+        .field(tr(language, "problem.tags"), tags_text, false)
+        .field(
+            tr(language, "problem.link"),
+            tr_args(language, "problem.solve_link", &[("url", &problem_url)]),
+            false,
+        )
+}
+
+// =====================
+//   API Error Messages
+// =====================
+
+// User-facing explanation for a failed Codeforces call
+pub fn api_error_message(language: Language, error: &CodeforcesError) -> String {
+    match error {
+        CodeforcesError::NotFound { comment } => {
+            tr_args(language, "api.not_found", &[("comment", comment)])
+        }
+        CodeforcesError::RateLimited => tr(language, "api.rate_limited").to_string(),
+        CodeforcesError::ApiFailed { comment } => {
+            tr_args(language, "api.failed", &[("comment", comment)])
+        }
+        CodeforcesError::Timeout => tr(language, "api.timeout").to_string(),
+        CodeforcesError::Network(_) | CodeforcesError::Decode(_) => {
+            tr(language, "api.network").to_string()
+        }
+    }
+}
@@ -2,170 +2,400 @@
 //   Imports and Dependencies
 // =====================
 
-use crate::api;
+use super::setrating::guild_rating_range;
+use super::{
+    api_error_message, problem_embed, rating_autocomplete, rating_option, MAX_PROBLEM_RATING,
+    MIN_PROBLEM_RATING,
+};
+use crate::api::codeforces::{CodeforcesError, ProblemFilter};
+use crate::api::problem_id::ProblemId;
+use crate::commands::context::{integer_option, string_option, CommandContext, Reply};
+use crate::tasks::daily::{DEFAULT_MAX_RATING, DEFAULT_MIN_RATING};
+use crate::Bot;
 use rand::seq::SliceRandom;
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use sqlx::PgPool;
-use std::sync::Arc;
+use serenity::builder::{CreateAutocompleteResponse, CreateCommand, CreateCommandOption};
+use serenity::model::application::{
+    AutocompleteOption, CommandOptionType, ResolvedOption, ResolvedValue,
+};
+use std::collections::{BTreeSet, HashSet};
+
+// Discord accepts at most 25 autocomplete choices
+const MAX_SUGGESTIONS: usize = 25;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /problem slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("problem")
+        .add_option(rating_option("rating", "Exact problem rating"))
+        .add_option(rating_option("min_rating", "Minimum problem rating"))
+        .add_option(rating_option("max_rating", "Maximum problem rating"))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "tags",
+                "Tags the problem must have (dp greedy binary_search)",
+            )
+            .set_autocomplete(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "exclude",
+                "Tags the problem must not have",
+            )
+            .set_autocomplete(true),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "unsolved",
+            "Only problems your linked handle hasn't solved",
+        ))
+}
+
+// Suggest ratings, or tags from the cached problemset, while the user types
+pub async fn autocomplete(
+    bot: &Bot,
+    option: &AutocompleteOption<'_>,
+) -> CreateAutocompleteResponse {
+    if option.name != "tags" && option.name != "exclude" {
+        return rating_autocomplete(bot, option).await;
+    }
+
+    let problems = match bot.problem_cache.problems().await {
+        Ok(problems) => problems,
+        Err(_) => return CreateAutocompleteResponse::new(),
+    };
+
+    // Complete the last tag being typed, keeping the ones before it
+    let typed = option.value.to_lowercase();
+    let (done, partial) = match typed.rsplit_once(char::is_whitespace) {
+        Some((done, partial)) => (format!("{} ", done.trim_end()), partial),
+        None => (String::new(), typed.as_str()),
+    };
+
+    let known_tags: BTreeSet<String> = problems
+        .iter()
+        .flat_map(|problem| problem.tags.iter())
+        .map(|tag| tag.replace(' ', "_"))
+        .collect();
+
+    known_tags
+        .into_iter()
+        .filter(|tag| tag.starts_with(partial))
+        .take(MAX_SUGGESTIONS)
+        .fold(CreateAutocompleteResponse::new(), |response, tag| {
+            let value = format!("{}{}", done, tag);
+            response.add_string_choice(value.clone(), value)
+        })
+}
+
+// =====================
+//   Problem Query
+// =====================
+
+// What the caller asked for
+#[derive(Debug, Default, PartialEq)]
+struct ProblemQuery {
+    // Inclusive rating bounds; None falls back to the guild's daily range
+    rating: Option<(u32, u32)>,
+    tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    // Skip problems the caller's linked handle has already solved
+    unsolved: bool,
+}
+
+impl ProblemQuery {
+    // Parse `[RATING | MIN MAX] [tag ...] [-tag ...] [unsolved]` in any order,
+    // returning the first argument that doesn't fit
+    fn parse(args: &str) -> Result<Self, String> {
+        let mut query = Self::default();
+        let mut ratings = Vec::new();
+
+        for word in args.split_whitespace() {
+            if word.eq_ignore_ascii_case("unsolved") {
+                query.unsolved = true;
+            } else if let Ok(rating) = word.parse::<u32>() {
+                ratings.push(rating);
+            } else if let Some((min, max)) = word
+                .split_once('-')
+                .and_then(|(min, max)| Some((min.parse::<u32>().ok()?, max.parse::<u32>().ok()?)))
+            {
+                // MIN-MAX written as a single word
+                ratings.extend([min, max]);
+            } else if let Some(tag) = word.strip_prefix('-').filter(|tag| !tag.is_empty()) {
+                query.excluded_tags.push(normalize_tag(tag));
+            } else {
+                query.tags.push(normalize_tag(word));
+            }
+        }
+
+        query.rating = match ratings.as_slice() {
+            [] => None,
+            [rating] => Some((*rating, *rating)),
+            [min, max] if min <= max => Some((*min, *max)),
+            [min, max] => return Err(format!("{} {}", min, max)),
+            [_, _, extra, ..] => return Err(extra.to_string()),
+        };
+
+        Ok(query)
+    }
+}
+
+// Tags are typed with underscores instead of spaces (`binary_search`)
+fn normalize_tag(tag: &str) -> String {
+    tag.trim_matches(',').replace('_', " ").to_lowercase()
+}
+
+// Split a slash command tag list (`dp, greedy binary_search`)
+fn tag_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split([' ', ','])
+        .filter(|tag| !tag.is_empty())
+        .map(normalize_tag)
+        .collect()
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!problem [RATING | MIN MAX] [tag ...] [-tag ...] [unsolved]`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    match ProblemQuery::parse(args) {
+        Ok(query) => run(ctx, query).await,
+        Err(argument) => Reply::text(ctx.tr_args(
+            "problem.incorrect_argument",
+            &[
+                ("argument", &argument),
+                ("usage", &ctx.usage()),
+                ("prefix", &ctx.settings.prefix),
+            ],
+        )),
+    }
+}
+
+// Handle `/problem [rating] [min_rating] [max_rating] [tags] [exclude] [unsolved]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let rating = match (
+        integer_option(options, "rating"),
+        integer_option(options, "min_rating"),
+        integer_option(options, "max_rating"),
+    ) {
+        (Some(rating), _, _) => Some((rating, rating)),
+        (None, None, None) => None,
+        // A single bound leaves the other side open
+        (None, min, max) => Some((
+            min.unwrap_or(MIN_PROBLEM_RATING.into()),
+            max.unwrap_or(MAX_PROBLEM_RATING.into()),
+        )),
+    };
+
+    if let Some((min, max)) = rating {
+        if min > max {
+            return Reply::text(ctx.tr("problem.min_above_max"));
+        }
+    }
+
+    let unsolved = options.iter().any(|option| {
+        option.name == "unsolved" && matches!(option.value, ResolvedValue::Boolean(true))
+    });
+
+    let query = ProblemQuery {
+        rating: rating.map(|(min, max)| (min as u32, max as u32)),
+        tags: tag_list(string_option(options, "tags")),
+        excluded_tags: tag_list(string_option(options, "exclude")),
+        unsolved,
+    };
+
+    run(ctx, query).await
+}
 
 // =====================
 //   Problem Command Implementation
 // =====================
 
-// Main function to handle the !problem command
-// Fetches a random Codeforces problem within specified difficulty range
-pub async fn execute(http: &Arc<Http>, msg: &Message, db: &PgPool) -> Result<(), serenity::Error> {
+// Fetches a random Codeforces problem matching the caller's filters
+async fn run(ctx: &CommandContext<'_>, query: ProblemQuery) -> Reply {
     // =====================
-    //   Command Input Parsing
+    //   Rating Range Resolution
     // =====================
 
-    // Suppress unused database parameter warning
-    let _ = db;
+    // Without explicit ratings, use the guild's daily range
+    let (min, max) = match (query.rating, ctx.guild_id) {
+        (Some(range), _) => range,
+        (None, Some(guild_id)) => match guild_rating_range(&ctx.bot.db, guild_id).await {
+            Ok((min, max)) => (min as u32, max as u32),
+            Err(e) => {
+                eprintln!("Database error reading rating range: {}", e);
+                return Reply::text(ctx.tr("problem.range_error"));
+            }
+        },
+        (None, None) => (DEFAULT_MIN_RATING as u32, DEFAULT_MAX_RATING as u32),
+    };
 
-    // Extract difficulty range from command (format: !problem MIN_RATING MAX_RATING)
-    let difficult = msg.content["!problem ".len()..].trim();
+    // =====================
+    //   Solved Problems Lookup
+    // =====================
 
-    // Parse min and max rating from user input
-    if let Some((min_str, max_str)) = difficult.split_once(' ') {
-        if let (Ok(min), Ok(max)) = (min_str.parse::<i32>(), max_str.parse::<i32>()) {
-            // =====================
-            //   Codeforces API Call
-            // =====================
+    // Problems the caller already solved, only needed for `unsolved`
+    let solved = if query.unsolved {
+        match solved_problems(ctx).await {
+            Ok(solved) => solved,
+            Err(reply) => return reply,
+        }
+    } else {
+        HashSet::new()
+    };
 
-            // Fetch problems from Codeforces API with specified rating range
-            let problems_result =
-                api::codeforces::get_problems(Some(min as u32), Some(max as u32)).await;
-
-            match problems_result {
-                Ok(problems_data) => {
-                    // =====================
-                    //   Problem Selection and Validation
-                    // =====================
-
-                    let problems = problems_data.result.problems;
-
-                    // Check if any problems were found in the specified range
-                    if problems.is_empty() {
-                        msg.channel_id
-                            .say(http, "❌ No problems found in that difficulty range")
-                            .await?;
-                        return Ok(());
-                    }
-
-                    // Prepare problems list for random selection
-                    let mut filtered_problems = problems;
-
-                    // Shuffle problems randomly in a separate scope to drop RNG before await
-                    {
-                        let mut rng = rand::rng();
-                        filtered_problems.shuffle(&mut rng);
-                    } // RNG dropped here to avoid holding across await points
-
-                    let problem = &filtered_problems[0];
-                    let rating = problem.rating.unwrap_or(0);
-
-                    // =====================
-                    //   Difficulty Visualization
-                    // =====================
-
-                    // Create difficulty bars based on problem rating
-                    let difficulty_bars = match rating {
-                        r if r <= 1000 => "🟩".repeat(1), // Beginner
-                        r if r <= 1400 => "🟩".repeat(2), // Easy
-                        r if r <= 1800 => "🟩".repeat(3), // Medium
-                        r if r <= 2200 => "🟩".repeat(4), // Hard
-                        _ => "🟩".repeat(5),              // Expert
-                    };
-
-                    // =====================
-                    //   Problem Information Formatting
-                    // =====================
-
-                    // Format problem tags for display
-                    let tags_text = if problem.tags.is_empty() {
-                        "No tags".to_string()
-                    } else {
-                        problem
-                            .tags
-                            .iter()
-                            .map(|tag| format!("`{}`", tag))
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    };
-
-                    // Generate Codeforces problem URL
-                    let problem_url = format!(
-                        "https://codeforces.com/problemset/problem/{}/{}",
-                        problem.contest_id.unwrap_or(0),
-                        problem.index
-                    );
-
-                    // =====================
-                    //   Discord Embed Creation
-                    // =====================
-
-                    // Create rich embed with problem information
-                    let embed = serenity::builder::CreateEmbed::new()
-                        .title("💻 Codeforces Problem")
-                        .description(format!("**{}**", problem.name))
-                        .field(
-                            "🎯 Difficulty",
-                            format!("{} **{}**", difficulty_bars, rating),
-                            true,
-                        )
-                        .field("🏷️ Tags", tags_text, false)
-                        .field(
-                            "🔗 Link",
-                            format!("[Solve problem]({})", problem_url),
-                            false,
-                        );
-
-                    // Create message with embed and user mention
-                    let message = serenity::builder::CreateMessage::new()
-                        .content(format!("Hello <@{}>! Here's your problem:", msg.author.id))
-                        .embed(embed);
-
-                    // Send the problem message to Discord
-                    msg.channel_id.send_message(http, message).await?;
-                }
-                Err(_) => {
-                    // =====================
-                    //   API Error Handling
-                    // =====================
-
-                    println!("Error obtaining problems");
-                    msg.channel_id
-                        .say(http, "❌ Error fetching problems from Codeforces")
-                        .await?;
-                }
-            }
+    // =====================
+    //   Codeforces API Call
+    // =====================
+
+    // Get cached problems matching the filters
+    let filter = ProblemFilter {
+        tags: query.tags,
+        excluded_tags: query.excluded_tags,
+        ..ProblemFilter::rating_range(Some(min), Some(max))
+    };
+    let problems_result = ctx
+        .bot
+        .problem_cache
+        .problems_matching(&filter)
+        .await
+        .map(|problems| {
+            problems
+                .into_iter()
+                .filter(|problem| problem.id().is_none_or(|id| !solved.contains(&id)))
+                .collect::<Vec<_>>()
+        });
 
-            Ok(())
-        } else {
+    let mut filtered_problems = match problems_result {
+        Ok(problems) => problems,
+        Err(e) => {
             // =====================
-            //   Invalid Rating Format Error
+            //   API Error Handling
             // =====================
 
-            msg.channel_id
-                .say(
-                    http,
-                    "❌ Incorrect format. Use: !problem MIN_RATING MAX_RATING",
-                )
-                .await?;
-            Ok(())
+            println!("Error obtaining problems: {}", e);
+            return Reply::text(api_error_message(ctx.language(), &e));
         }
-    } else {
-        // =====================
-        //   Missing Parameters Error
-        // =====================
-
-        msg.channel_id
-            .say(
-                http,
-                "❌ Incorrect format. Use: !problem MIN_RATING MAX_RATING",
-            )
-            .await?;
-        Ok(())
+    };
+
+    // =====================
+    //   Problem Selection and Validation
+    // =====================
+
+    // Check if any problems matched the filters
+    if filtered_problems.is_empty() {
+        return Reply::text(ctx.tr("problem.no_results"));
+    }
+
+    // Shuffle problems randomly in a separate scope to drop RNG before any await
+    {
+        let mut rng = rand::rng();
+        filtered_problems.shuffle(&mut rng);
+    }
+
+    let problem = &filtered_problems[0];
+
+    // =====================
+    //   Discord Embed Creation
+    // =====================
+
+    // Create rich embed with problem information
+    let embed = problem_embed(ctx.language(), ctx.tr("problem.title"), problem);
+
+    // Reply with the embed and a mention of the user
+    Reply::embed(embed).with_content(ctx.tr_args("problem.greeting", &[("user", &ctx.author.id)]))
+}
+
+// Accepted problems of the caller's linked handle
+async fn solved_problems(ctx: &CommandContext<'_>) -> Result<HashSet<ProblemId>, Reply> {
+    let Some(guild_id) = ctx.guild_id else {
+        return Err(Reply::text(ctx.tr("common.guild_only")));
+    };
+
+    let handle = match sqlx::query_scalar::<_, Option<String>>(
+        "SELECT codeforces_handle FROM user_info WHERE guild_id = $1 AND user_id = $2",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(Some(Some(handle))) => handle,
+        Ok(_) => {
+            return Err(Reply::text(ctx.tr_args(
+                "problem.unsolved_needs_account",
+                &[("prefix", &ctx.settings.prefix)],
+            )));
+        }
+        Err(e) => {
+            eprintln!("Database error getting user handle: {}", e);
+            return Err(Reply::text(ctx.tr("problem.account_error")));
+        }
+    };
+
+    match ctx
+        .bot
+        .codeforces
+        .get_user_status(&handle, None, None)
+        .await
+    {
+        Ok(submissions) => Ok(submissions
+            .iter()
+            .filter(|submission| submission.is_accepted())
+            .filter_map(|submission| submission.problem.id())
+            .collect()),
+        Err(CodeforcesError::NotFound { .. }) => Err(Reply::text(
+            ctx.tr_args("common.handle_gone", &[("handle", &handle)]),
+        )),
+        Err(e) => {
+            eprintln!("Codeforces API error in problem command: {}", e);
+            Err(Reply::text(api_error_message(ctx.language(), &e)))
+        }
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ratings_tags_and_flags() {
+        let query = ProblemQuery::parse("1200 1600 dp binary_search -math unsolved").unwrap();
+        assert_eq!(
+            query,
+            ProblemQuery {
+                rating: Some((1200, 1600)),
+                tags: vec!["dp".to_string(), "binary search".to_string()],
+                excluded_tags: vec!["math".to_string()],
+                unsolved: true,
+            }
+        );
+
+        assert_eq!(
+            ProblemQuery::parse("1500").unwrap().rating,
+            Some((1500, 1500))
+        );
+        assert_eq!(
+            ProblemQuery::parse("greedy 800-1000").unwrap().rating,
+            Some((800, 1000))
+        );
+        assert_eq!(ProblemQuery::parse("").unwrap(), ProblemQuery::default());
+    }
+
+    #[test]
+    fn rejects_bad_rating_arguments() {
+        assert!(ProblemQuery::parse("1600 1200").is_err());
+        assert!(ProblemQuery::parse("800 900 1000").is_err());
     }
 }
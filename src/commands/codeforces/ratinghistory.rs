@@ -0,0 +1,180 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use super::api_error_message;
+use crate::api::codeforces::{CodeforcesError, RatingChange};
+use crate::commands::context::{mentioned_user, user_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use serenity::model::id::UserId;
+
+// Number of most recent contests listed in the embed
+const RECENT_CONTESTS: usize = 5;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /ratinghistory slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("ratinghistory").add_option(CreateCommandOption::new(
+        CommandOptionType::User,
+        "user",
+        "Member to look up (defaults to you)",
+    ))
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!ratinghistory [@user]`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    // Use the first mentioned member, or the author when nobody is mentioned
+    let target = mentioned_user(args).unwrap_or(ctx.author.id);
+
+    run(ctx, target).await
+}
+
+// Handle `/ratinghistory [user]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let target = user_option(options, "user").map_or(ctx.author.id, |user| user.id);
+    run(ctx, target).await
+}
+
+// =====================
+//   Rating History Command Implementation
+// =====================
+
+// Shows the rating history of the caller (or of another member) from their linked handle
+async fn run(ctx: &CommandContext<'_>, target: UserId) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Get Linked Codeforces Handle
+    // =====================
+
+    let handle = match sqlx::query_scalar::<_, Option<String>>(
+        "SELECT codeforces_handle FROM user_info WHERE guild_id = $1 AND user_id = $2",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(target.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(Some(Some(handle))) => handle,
+        Ok(_) => {
+            let response = if target == ctx.author.id {
+                ctx.tr_args(
+                    "common.no_linked_account",
+                    &[("prefix", &ctx.settings.prefix)],
+                )
+            } else {
+                ctx.tr_args("common.member_not_linked", &[("user", &target)])
+            };
+            return Reply::text(response);
+        }
+        Err(e) => {
+            eprintln!("Database error in ratinghistory command: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    // =====================
+    //   Fetch Rating History
+    // =====================
+
+    let history = match ctx.bot.codeforces.get_user_rating(&handle).await {
+        Ok(history) => history,
+        Err(CodeforcesError::NotFound { .. }) => {
+            return Reply::text(ctx.tr_args("common.handle_gone", &[("handle", &handle)]));
+        }
+        Err(e) => {
+            eprintln!("Codeforces API error in ratinghistory command: {}", e);
+            return Reply::text(api_error_message(ctx.language(), &e));
+        }
+    };
+
+    // Nothing to summarise for users who never took part in a rated contest
+    let Some(summary) = RatingSummary::from_history(&history) else {
+        return Reply::text(ctx.tr_args("ratinghistory.no_contests", &[("handle", &handle)]));
+    };
+
+    // =====================
+    //   Discord Embed Creation
+    // =====================
+
+    // Most recent contests first
+    let recent_text = history
+        .iter()
+        .rev()
+        .take(RECENT_CONTESTS)
+        .map(|change| {
+            format!(
+                "`{:+}` → **{}** · [{}](https://codeforces.com/contest/{}) (#{})",
+                change.delta(),
+                change.new_rating,
+                change.contest_name,
+                change.contest_id,
+                change.rank
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = serenity::builder::CreateEmbed::new()
+        .title(ctx.tr_args("ratinghistory.title", &[("handle", &handle)]))
+        .url(format!("https://codeforces.com/profile/{}", handle))
+        .field(
+            ctx.tr("ratinghistory.contests"),
+            summary.contests.to_string(),
+            true,
+        )
+        .field(
+            ctx.tr("ratinghistory.current"),
+            ctx.tr_args(
+                "ratinghistory.current_value",
+                &[("current", &summary.current), ("max", &summary.max)],
+            ),
+            true,
+        )
+        .field(
+            ctx.tr("ratinghistory.deltas"),
+            format!("`{:+}` / `{:+}`", summary.best_delta, summary.worst_delta),
+            true,
+        )
+        .field(ctx.tr("ratinghistory.recent"), recent_text, false);
+
+    Reply::embed(embed)
+}
+
+// =====================
+//   Rating Summary
+// =====================
+
+// Aggregated numbers shown at the top of the embed
+struct RatingSummary {
+    contests: usize,
+    current: i32,
+    max: i32,
+    best_delta: i32,
+    worst_delta: i32,
+}
+
+impl RatingSummary {
+    // Summarise a history ordered oldest first; None when the history is empty
+    fn from_history(history: &[RatingChange]) -> Option<Self> {
+        let last = history.last()?;
+
+        Some(Self {
+            contests: history.len(),
+            current: last.new_rating,
+            max: history.iter().map(|c| c.new_rating).max()?,
+            best_delta: history.iter().map(RatingChange::delta).max()?,
+            worst_delta: history.iter().map(RatingChange::delta).min()?,
+        })
+    }
+}
@@ -2,84 +2,91 @@
 //   Imports and Dependencies
 // =====================
 
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use sqlx::PgPool;
-use std::sync::Arc;
+use crate::commands::context::{channel_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use serenity::model::channel::ChannelType;
+use serenity::model::id::ChannelId;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setchannel slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setchannel").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Channel,
+            "channel",
+            "Channel for the daily problem",
+        )
+        .channel_types(vec![ChannelType::Text, ChannelType::News])
+        .required(true),
+    )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setchannel #channel`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    // Validate mention format: <#ID> and extract channel ID
+    let Some(id_str) = args.strip_prefix("<#").and_then(|s| s.strip_suffix(">")) else {
+        return Reply::text(ctx.tr_args("setchannel.incorrect_format", &[("usage", &ctx.usage())]));
+    };
+
+    match id_str.parse::<u64>() {
+        Ok(id) if id != 0 => run(ctx, ChannelId::new(id)).await,
+        _ => Reply::text(ctx.tr("setchannel.invalid_channel")),
+    }
+}
+
+// Handle `/setchannel channel`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    match channel_option(options, "channel") {
+        Some(channel_id) => run(ctx, channel_id).await,
+        None => Reply::text(ctx.tr("setchannel.invalid_channel")),
+    }
+}
 
 // =====================
 //   Set Channel Command Implementation
 // =====================
 
-// Main function to handle the !setchannel command
 // Configures which channel will receive daily Codeforces problems
-pub async fn execute(http: &Arc<Http>, msg: &Message, db: &PgPool) -> Result<(), serenity::Error> {
+async fn run(ctx: &CommandContext<'_>, channel_id: ChannelId) -> Reply {
     // =====================
-    //   Command Input Parsing
+    //   Database Operations
     // =====================
 
-    // Extract the channel mention from the command
-    let mention = msg.content["!setchannel ".len()..].trim();
+    // Get guild ID for database update
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // Update daily channel ID in guild configuration
+    let result = sqlx::query(
+        "UPDATE guild_config
+         SET daily_channel_id = $1
+         WHERE guild_id = $2",
+    )
+    .bind(channel_id.get() as i64)
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
 
     // =====================
-    //   Channel ID Validation
+    //   Success/Error Response
     // =====================
 
-    // Validate mention format: <#ID> and extract channel ID
-    if let Some(id_str) = mention.strip_prefix("<#").and_then(|s| s.strip_suffix(">")) {
-        if let Ok(channel_id) = id_str.parse::<i64>() {
-            // =====================
-            //   Database Operations
-            // =====================
-
-            // Get guild ID for database update
-            let guild_id: i64 = msg.guild_id.unwrap().get() as i64;
-
-            // Update daily channel ID in guild configuration
-            let result = sqlx::query(
-                "UPDATE guild_config
-                 SET daily_channel_id = $1
-                 WHERE guild_id = $2",
-            )
-            .bind(channel_id)
-            .bind(guild_id)
-            .execute(db)
-            .await;
-
-            // =====================
-            //   Success/Error Response
-            // =====================
-
-            match result {
-                Ok(_) => {
-                    // Success: Confirm channel has been configured
-                    let response = format!("✅ Daily report channel set to <#{}>", channel_id);
-                    msg.channel_id.say(http, response).await?;
-                }
-                Err(_) => {
-                    // Database error occurred
-                    println!("Error saving channel");
-                    msg.channel_id
-                        .say(http, "❌ Error saving the channel")
-                        .await?;
-                }
-            }
-        } else {
-            // =====================
-            //   Invalid Channel ID Error
-            // =====================
-
-            msg.channel_id.say(http, "❌ Invalid channel ID").await?;
+    match result {
+        // Success: Confirm channel has been configured
+        Ok(_) => Reply::text(ctx.tr_args("setchannel.saved", &[("channel", &channel_id)])),
+        Err(_) => {
+            // Database error occurred
+            println!("Error saving channel");
+            Reply::text(ctx.tr("setchannel.save_error"))
         }
-    } else {
-        // =====================
-        //   Invalid Format Error
-        // =====================
-
-        msg.channel_id
-            .say(http, "❌ Incorrect format. Use: !setchannel #channel")
-            .await?;
     }
-
-    Ok(())
 }
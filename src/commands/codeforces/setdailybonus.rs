@@ -0,0 +1,96 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{integer_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+
+// Largest bonus a guild can configure
+const MAX_DAILY_BONUS: i64 = 100;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setdailybonus slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setdailybonus").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Integer,
+            "coins",
+            "Extra coins for solving the daily problem (0 disables the bonus)",
+        )
+        .min_int_value(0)
+        .max_int_value(MAX_DAILY_BONUS as u64)
+        .required(true),
+    )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setdailybonus COINS`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    match args.parse::<i64>() {
+        Ok(coins) => run(ctx, coins).await,
+        Err(_) => {
+            Reply::text(ctx.tr_args("setdailybonus.incorrect_format", &[("usage", &ctx.usage())]))
+        }
+    }
+}
+
+// Handle `/setdailybonus coins`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    match integer_option(options, "coins") {
+        Some(coins) => run(ctx, coins).await,
+        None => {
+            Reply::text(ctx.tr_args("setdailybonus.incorrect_format", &[("usage", &ctx.usage())]))
+        }
+    }
+}
+
+// =====================
+//   Set Daily Bonus Command Implementation
+// =====================
+
+// Sets the extra coins earned by solving the guild's daily problem
+async fn run(ctx: &CommandContext<'_>, coins: i64) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    if !(0..=MAX_DAILY_BONUS).contains(&coins) {
+        return Reply::text(
+            ctx.tr_args("setdailybonus.out_of_range", &[("max", &MAX_DAILY_BONUS)]),
+        );
+    }
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET daily_bonus = $1
+        WHERE guild_id = $2",
+    )
+    .bind(coins as i32)
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) if coins == 0 => Reply::text(ctx.tr("setdailybonus.disabled")),
+        Ok(_) => Reply::text(ctx.tr_args("setdailybonus.saved", &[("coins", &coins)])),
+        Err(e) => {
+            eprintln!("Error saving daily bonus: {}", e);
+            Reply::text(ctx.tr("setdailybonus.save_error"))
+        }
+    }
+}
@@ -2,103 +2,115 @@
 //   Imports and Dependencies
 // =====================
 
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use sqlx::PgPool;
-use std::sync::Arc;
+use crate::commands::context::{integer_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /sethour slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("sethour")
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "hour", "Hour (00-23)")
+                .min_int_value(0)
+                .max_int_value(23)
+                .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "minute", "Minute (00-59)")
+                .min_int_value(0)
+                .max_int_value(59),
+        )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!sethour HH:MM` (also `!sethora`)
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    // Parse time format (HH:MM)
+    let Some((horas_str, minutos_str)) = args.split_once(':') else {
+        return incorrect_format(ctx);
+    };
+
+    match (horas_str.parse::<i32>(), minutos_str.parse::<i32>()) {
+        (Ok(horas), Ok(minutos)) => run(ctx, horas, minutos).await,
+        _ => incorrect_format(ctx),
+    }
+}
+
+// Handle `/sethour hour [minute]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let Some(horas) = integer_option(options, "hour") else {
+        return incorrect_format(ctx);
+    };
+    let minutos = integer_option(options, "minute").unwrap_or(0);
+
+    run(ctx, horas as i32, minutos as i32).await
+}
+
+// Reply for arguments that don't match the expected format
+fn incorrect_format(ctx: &CommandContext<'_>) -> Reply {
+    Reply::text(ctx.tr_args(
+        "sethour.incorrect_format",
+        &[("usage", &ctx.usage()), ("prefix", &ctx.settings.prefix)],
+    ))
+}
 
 // =====================
 //   Set Hour Command Implementation
 // =====================
 
-// Main function to handle the !sethora command
-// Sets the time for daily problem posting in HH:MM format
-pub async fn execute(http: &Arc<Http>, msg: &Message, db: &PgPool) -> Result<(), serenity::Error> {
+// Sets the time for daily problem posting
+async fn run(ctx: &CommandContext<'_>, horas: i32, minutos: i32) -> Reply {
     // =====================
-    //   Command Input Parsing
+    //   Time Validation
     // =====================
 
-    // Extract the time after the command
-    let hora = msg.content["!sethora ".len()..].trim();
+    // Validate hour and minute ranges (24-hour format)
+    if !(0..24).contains(&horas) || !(0..60).contains(&minutos) {
+        return Reply::text(ctx.tr("sethour.invalid_time"));
+    }
 
-    // Parse time format (HH:MM)
-    if let Some((horas_str, minutos_str)) = hora.split_once(':') {
-        if let (Ok(horas), Ok(minutos)) = (horas_str.parse::<i32>(), minutos_str.parse::<i32>()) {
-            // =====================
-            //   Time Validation
-            // =====================
-
-            // Validate hour and minute ranges (24-hour format)
-            if horas >= 0 && horas < 24 && minutos >= 0 && minutos < 60 {
-                // =====================
-                //   Database Operations
-                // =====================
-
-                // Get guild ID for database update
-                let guild_id = msg.guild_id.unwrap().get() as i64;
-
-                // Update daily hour and minute in guild configuration
-                let result = sqlx::query(
-                    "UPDATE guild_config
-                    SET daily_hour = $1, daily_minute = $2
-                    WHERE guild_id = $3",
-                )
-                .bind(horas)
-                .bind(minutos)
-                .bind(guild_id)
-                .execute(db)
-                .await;
-
-                // =====================
-                //   Success/Error Response
-                // =====================
-
-                match result {
-                    Ok(_) => {
-                        // Success: Confirm time has been set
-                        let response =
-                            format!("⏰ Daily report time set to {:02}:{:02}", horas, minutos);
-                        msg.channel_id.say(http, response).await?;
-                    }
-                    Err(_) => {
-                        // Database error occurred
-                        println!("Error saving time");
-                        msg.channel_id.say(http, "❌ Error saving the time").await?;
-                    }
-                }
-            } else {
-                // =====================
-                //   Invalid Time Range Error
-                // =====================
-
-                msg.channel_id
-                    .say(http, "❌ Invalid time. Use HH:MM format (00:00 - 23:59)")
-                    .await?;
-            }
-        } else {
-            // =====================
-            //   Invalid Format Error
-            // =====================
-
-            msg.channel_id
-                .say(
-                    http,
-                    "❌ Incorrect format. Use: !sethora HH:MM (example: !sethora 09:00)",
-                )
-                .await?;
+    // =====================
+    //   Database Operations
+    // =====================
+
+    // Get guild ID for database update
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // Update daily hour and minute in guild configuration
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET daily_hour = $1, daily_minute = $2
+        WHERE guild_id = $3",
+    )
+    .bind(horas)
+    .bind(minutos)
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        // Success: Confirm time has been set
+        Ok(_) => Reply::text(ctx.tr_args(
+            "sethour.saved",
+            &[("time", &format!("{:02}:{:02}", horas, minutos))],
+        )),
+        Err(_) => {
+            // Database error occurred
+            println!("Error saving time");
+            Reply::text(ctx.tr("sethour.save_error"))
         }
-    } else {
-        // =====================
-        //   Missing Colon Separator Error
-        // =====================
-
-        msg.channel_id
-            .say(
-                http,
-                "❌ Incorrect format. Use: !sethora HH:MM (example: !sethora 09:00)",
-            )
-            .await?;
     }
-
-    Ok(())
 }
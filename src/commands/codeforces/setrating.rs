@@ -0,0 +1,231 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use super::{rating_option, MAX_PROBLEM_RATING, MIN_PROBLEM_RATING};
+use crate::commands::context::{integer_option, CommandContext, Reply};
+use crate::i18n::{tr_args, Language};
+use crate::tasks::daily::{DEFAULT_MAX_RATING, DEFAULT_MIN_RATING};
+use serenity::builder::CreateCommand;
+use serenity::model::application::ResolvedOption;
+use serenity::model::id::GuildId;
+use sqlx::{PgPool, Row};
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setrating slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setrating")
+        .add_option(rating_option(
+            "min_rating",
+            "Minimum rating of daily problems (leave both empty to view)",
+        ))
+        .add_option(rating_option(
+            "max_rating",
+            "Maximum rating of daily problems",
+        ))
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setrating` (view) and `!setrating MIN MAX` (set)
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    if args.is_empty() {
+        return show(ctx).await;
+    }
+
+    let parts: Vec<&str> = args.split_whitespace().collect();
+    match parts.as_slice() {
+        [min_str, max_str] => match (min_str.parse::<i64>(), max_str.parse::<i64>()) {
+            (Ok(min), Ok(max)) => update(ctx, min, max).await,
+            _ => incorrect_format(ctx),
+        },
+        _ => incorrect_format(ctx),
+    }
+}
+
+// Handle `/setrating [min_rating max_rating]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    match (
+        integer_option(options, "min_rating"),
+        integer_option(options, "max_rating"),
+    ) {
+        (None, None) => show(ctx).await,
+        (Some(min), Some(max)) => update(ctx, min, max).await,
+        _ => Reply::text(ctx.tr("setrating.missing_bound")),
+    }
+}
+
+// Reply for arguments that don't match the expected format
+fn incorrect_format(ctx: &CommandContext<'_>) -> Reply {
+    Reply::text(ctx.tr_args(
+        "setrating.incorrect_format",
+        &[("usage", &ctx.usage()), ("prefix", &ctx.settings.prefix)],
+    ))
+}
+
+// =====================
+//   Rating Range Validation
+// =====================
+
+// Check a daily rating range, explaining what is wrong with it
+pub fn validate_rating_range(language: Language, min: i64, max: i64) -> Result<(i32, i32), String> {
+    let allowed = i64::from(MIN_PROBLEM_RATING)..=i64::from(MAX_PROBLEM_RATING);
+
+    for rating in [min, max] {
+        if !allowed.contains(&rating) {
+            return Err(tr_args(
+                language,
+                "setrating.out_of_range",
+                &[
+                    ("rating", &rating),
+                    ("min", &MIN_PROBLEM_RATING),
+                    ("max", &MAX_PROBLEM_RATING),
+                ],
+            ));
+        }
+        if rating % 100 != 0 {
+            return Err(tr_args(
+                language,
+                "setrating.not_multiple",
+                &[("rating", &rating)],
+            ));
+        }
+    }
+
+    if min > max {
+        return Err(tr_args(
+            language,
+            "setrating.min_above_max",
+            &[("min", &min), ("max", &max)],
+        ));
+    }
+
+    Ok((min as i32, max as i32))
+}
+
+// The guild's daily rating range, or the defaults when it was never set
+pub async fn guild_rating_range(db: &PgPool, guild_id: GuildId) -> Result<(i32, i32), sqlx::Error> {
+    let row = sqlx::query("SELECT min_rating, max_rating FROM guild_config WHERE guild_id = $1")
+        .bind(guild_id.get() as i64)
+        .fetch_optional(db)
+        .await?;
+
+    let Some(row) = row else {
+        return Ok((DEFAULT_MIN_RATING, DEFAULT_MAX_RATING));
+    };
+
+    Ok((
+        row.try_get::<Option<i32>, _>("min_rating")?
+            .unwrap_or(DEFAULT_MIN_RATING),
+        row.try_get::<Option<i32>, _>("max_rating")?
+            .unwrap_or(DEFAULT_MAX_RATING),
+    ))
+}
+
+// =====================
+//   Set Rating Command Implementation
+// =====================
+
+// Show the guild's current daily rating range
+async fn show(ctx: &CommandContext<'_>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    match guild_rating_range(&ctx.bot.db, guild_id).await {
+        Ok((min, max)) => Reply::text(ctx.tr_args(
+            "setrating.current",
+            &[("min", &min), ("max", &max), ("usage", &ctx.usage())],
+        )),
+        Err(e) => {
+            eprintln!("Error reading rating range: {}", e);
+            Reply::text(ctx.tr("setrating.read_error"))
+        }
+    }
+}
+
+// Validate and store a new daily rating range
+async fn update(ctx: &CommandContext<'_>, min: i64, max: i64) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    let (min, max) = match validate_rating_range(ctx.language(), min, max) {
+        Ok(range) => range,
+        Err(reason) => return Reply::text(reason),
+    };
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET min_rating = $1, max_rating = $2
+        WHERE guild_id = $3",
+    )
+    .bind(min)
+    .bind(max)
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) => Reply::text(ctx.tr_args("setrating.saved", &[("min", &min), ("max", &max)])),
+        Err(e) => {
+            eprintln!("Error saving rating range: {}", e);
+            Reply::text(ctx.tr("setrating.save_error"))
+        }
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_ranges() {
+        assert_eq!(
+            validate_rating_range(Language::En, 800, 1400),
+            Ok((800, 1400))
+        );
+        assert_eq!(
+            validate_rating_range(Language::En, 1500, 1500),
+            Ok((1500, 1500))
+        );
+        assert_eq!(
+            validate_rating_range(Language::En, 800, 3500),
+            Ok((800, 3500))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        // Out of range, not a multiple of 100, min above max
+        for (min, max) in [
+            (700, 1200),
+            (800, 3600),
+            (850, 1200),
+            (800, 1250),
+            (1600, 1200),
+        ] {
+            assert!(
+                validate_rating_range(Language::En, min, max).is_err(),
+                "{min}-{max} should be rejected"
+            );
+        }
+    }
+}
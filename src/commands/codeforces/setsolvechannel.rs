@@ -0,0 +1,96 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{channel_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use serenity::model::channel::ChannelType;
+use serenity::model::id::ChannelId;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setsolvechannel slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setsolvechannel").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Channel,
+            "channel",
+            "Channel for solve announcements (leave empty to disable them)",
+        )
+        .channel_types(vec![ChannelType::Text, ChannelType::News]),
+    )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setsolvechannel #channel` and `!setsolvechannel none`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    if args.eq_ignore_ascii_case("none") {
+        return run(ctx, None).await;
+    }
+
+    // Validate mention format: <#ID> and extract channel ID
+    let Some(id_str) = args.strip_prefix("<#").and_then(|s| s.strip_suffix('>')) else {
+        return Reply::text(ctx.tr_args(
+            "setsolvechannel.incorrect_format",
+            &[("usage", &ctx.usage())],
+        ));
+    };
+
+    match id_str.parse::<u64>() {
+        Ok(id) if id != 0 => run(ctx, Some(ChannelId::new(id))).await,
+        _ => Reply::text(ctx.tr("setchannel.invalid_channel")),
+    }
+}
+
+// Handle `/setsolvechannel [channel]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(ctx, channel_option(options, "channel")).await
+}
+
+// =====================
+//   Set Solve Channel Command Implementation
+// =====================
+
+// Configures (or disables) the channel where automatically detected solves are announced
+async fn run(ctx: &CommandContext<'_>, channel_id: Option<ChannelId>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET solve_channel_id = $1
+        WHERE guild_id = $2",
+    )
+    .bind(channel_id.map(|id| id.get() as i64))
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) => match channel_id {
+            Some(channel_id) => {
+                Reply::text(ctx.tr_args("setsolvechannel.saved", &[("channel", &channel_id)]))
+            }
+            None => Reply::text(ctx.tr("setsolvechannel.disabled")),
+        },
+        Err(e) => {
+            eprintln!("Error saving solve channel: {}", e);
+            Reply::text(ctx.tr("setsolvechannel.save_error"))
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{integer_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setsolvedthreshold slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setsolvedthreshold").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Integer,
+            "percent",
+            "Skip daily problems solved by more than this % of linked members (empty disables)",
+        )
+        .min_int_value(0)
+        .max_int_value(100),
+    )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setsolvedthreshold PERCENT` and `!setsolvedthreshold off`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    if args.eq_ignore_ascii_case("off") {
+        return run(ctx, None).await;
+    }
+
+    match args.trim_end_matches('%').parse::<i64>() {
+        Ok(percent) => run(ctx, Some(percent)).await,
+        Err(_) => Reply::text(ctx.tr_args(
+            "setsolvedthreshold.incorrect_format",
+            &[("usage", &ctx.usage())],
+        )),
+    }
+}
+
+// Handle `/setsolvedthreshold [percent]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(ctx, integer_option(options, "percent")).await
+}
+
+// =====================
+//   Set Solved Threshold Command Implementation
+// =====================
+
+// Sets (or clears) the share of linked members above which a solved problem isn't picked as daily
+async fn run(ctx: &CommandContext<'_>, percent: Option<i64>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    if percent.is_some_and(|percent| !(0..=100).contains(&percent)) {
+        return Reply::text(ctx.tr("setsolvedthreshold.out_of_range"));
+    }
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET daily_solved_threshold = $1
+        WHERE guild_id = $2",
+    )
+    .bind(percent.map(|percent| percent as i32))
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) => match percent {
+            Some(percent) => {
+                Reply::text(ctx.tr_args("setsolvedthreshold.saved", &[("percent", &percent)]))
+            }
+            None => Reply::text(ctx.tr("setsolvedthreshold.disabled")),
+        },
+        Err(e) => {
+            eprintln!("Error saving solved threshold: {}", e);
+            Reply::text(ctx.tr("setsolvedthreshold.save_error"))
+        }
+    }
+}
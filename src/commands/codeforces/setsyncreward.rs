@@ -0,0 +1,84 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{boolean_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /setsyncreward slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("setsyncreward").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "enabled",
+            "Whether problems imported with /sync award coins",
+        )
+        .required(true),
+    )
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!setsyncreward on` and `!setsyncreward off`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    match args.to_lowercase().as_str() {
+        "on" => run(ctx, true).await,
+        "off" => run(ctx, false).await,
+        _ => Reply::text(ctx.tr_args("setsyncreward.incorrect_format", &[("usage", &ctx.usage())])),
+    }
+}
+
+// Handle `/setsyncreward enabled`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    match boolean_option(options, "enabled") {
+        Some(enabled) => run(ctx, enabled).await,
+        None => {
+            Reply::text(ctx.tr_args("setsyncreward.incorrect_format", &[("usage", &ctx.usage())]))
+        }
+    }
+}
+
+// =====================
+//   Set Sync Reward Command Implementation
+// =====================
+
+// Chooses whether historical solves imported by `!sync` award coins
+async fn run(ctx: &CommandContext<'_>, enabled: bool) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Database Operations
+    // =====================
+
+    let result = sqlx::query(
+        "UPDATE guild_config
+        SET sync_awards_coins = $1
+        WHERE guild_id = $2",
+    )
+    .bind(enabled)
+    .bind(guild_id.get() as i64)
+    .execute(&ctx.bot.db)
+    .await;
+
+    // =====================
+    //   Success/Error Response
+    // =====================
+
+    match result {
+        Ok(_) if enabled => Reply::text(ctx.tr("setsyncreward.enabled")),
+        Ok(_) => Reply::text(ctx.tr("setsyncreward.disabled")),
+        Err(e) => {
+            eprintln!("Error saving sync reward policy: {}", e);
+            Reply::text(ctx.tr("setsyncreward.save_error"))
+        }
+    }
+}
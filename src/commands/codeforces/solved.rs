@@ -2,77 +2,119 @@
 //   Imports and Dependencies
 // =====================
 
-use crate::api;
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use serenity::model::guild::PartialGuild;
+use super::api_error_message;
+use crate::api::codeforces::{CodeforcesError, Submission};
+use crate::api::problem_id::ProblemId;
+use crate::commands::context::{string_option, CommandContext, Reply};
+use crate::tasks::daily::daily_bonus;
+use crate::Bot;
+use serenity::builder::{CreateAutocompleteResponse, CreateCommand, CreateCommandOption};
+use serenity::model::application::{AutocompleteOption, CommandOptionType, ResolvedOption};
 use sqlx::PgPool;
-use std::sync::Arc;
+
+// Discord accepts at most 25 autocomplete choices
+const MAX_SUGGESTIONS: usize = 25;
+
+// Coins awarded for each newly solved problem (also by automatic solve detection)
+pub const SOLVE_REWARD: i64 = 1;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /solved slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("solved").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "problem",
+            "Problem id (467B) or problem link",
+        )
+        .set_autocomplete(true)
+        .required(true),
+    )
+}
+
+// Suggest problem ids from the cached problemset matching what was typed so far
+pub async fn autocomplete(
+    bot: &Bot,
+    option: &AutocompleteOption<'_>,
+) -> CreateAutocompleteResponse {
+    let typed: String = option
+        .value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_uppercase();
+
+    // Nothing useful to suggest until a contest id is being typed
+    if typed.is_empty() {
+        return CreateAutocompleteResponse::new();
+    }
+
+    let problems = match bot.problem_cache.problems().await {
+        Ok(problems) => problems,
+        Err(_) => return CreateAutocompleteResponse::new(),
+    };
+
+    problems
+        .iter()
+        .filter_map(|problem| Some((problem.id()?, &problem.name)))
+        .filter(|(id, _)| id.to_string().starts_with(&typed))
+        .take(MAX_SUGGESTIONS)
+        .fold(CreateAutocompleteResponse::new(), |response, (id, name)| {
+            // Choice names are limited to 100 characters
+            let label: String = format!("{} - {}", id, name).chars().take(100).collect();
+            response.add_string_choice(label, id.to_string())
+        })
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!solved 467B`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    run(ctx, args).await
+}
+
+// Handle `/solved problem`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(
+        ctx,
+        string_option(options, "problem").unwrap_or_default().trim(),
+    )
+    .await
+}
 
 // =====================
 //   Solved Command Implementation
 // =====================
 
-// Main function to handle the !solved command
 // Verifies if a user has solved a specific Codeforces problem and marks it as solved
-pub async fn execute(
-    http: &Arc<Http>,
-    msg: &Message,
-    db: &PgPool,
-    guild: &PartialGuild,
-) -> Result<(), serenity::Error> {
-    // =====================
-    //   Extract Problem ID
-    // =====================
-
-    // Extract problem ID from message content after "!solved "
-    let problem_id = msg.content["!solved ".len()..].trim();
+async fn run(ctx: &CommandContext<'_>, argument: &str) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+    let db = &ctx.bot.db;
 
     // Check if problem ID was provided
-    if problem_id.is_empty() {
-        let _ = msg
-            .channel_id
-            .say(
-                http,
-                "❌ Por favor proporciona un ID de problema. Uso: `!solved 467B`",
-            )
-            .await;
-        return Ok(());
+    if argument.is_empty() {
+        return Reply::text(ctx.tr_args(
+            "solved.missing_problem",
+            &[("usage", &ctx.usage()), ("prefix", &ctx.settings.prefix)],
+        ));
     }
 
     // =====================
-    //   Parse Contest ID and Index
+    //   Parse Problem ID
     // =====================
 
-    // Find where the digits end and letters begin
-    let split_point = problem_id
-        .chars()
-        .position(|c| !c.is_ascii_digit())
-        .unwrap_or(problem_id.len());
-
-    if split_point == 0 || split_point == problem_id.len() {
-        let _ = msg
-            .channel_id
-            .say(
-                http,
-                "❌ Formato de problema inválido. Usa el formato: `467B` (contest_id + index)",
-            )
-            .await;
-        return Ok(());
-    }
-
-    let contest_id_str = &problem_id[..split_point];
-    let index = &problem_id[split_point..];
-
-    // Parse contest ID to number
-    let contest_id: u32 = match contest_id_str.parse() {
+    // Accepts 467B, CF467B, gym ids (100001A), subproblems (1850A1) and problem URLs
+    let problem_id: ProblemId = match argument.parse() {
         Ok(id) => id,
         Err(_) => {
-            let _ = msg
-                .channel_id
-                .say(http, "❌ Contest ID inválido. Debe ser un número.")
-                .await;
-            return Ok(());
+            return Reply::text(ctx.tr("solved.invalid_format"));
         }
     };
 
@@ -84,32 +126,21 @@ pub async fn execute(
     let user_handle = match sqlx::query_scalar::<_, String>(
         "SELECT codeforces_handle FROM user_info WHERE guild_id = $1 AND user_id = $2",
     )
-    .bind(guild.id.get() as i64)
-    .bind(msg.author.id.get() as i64)
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
     .fetch_optional(db)
     .await
     {
         Ok(Some(handle)) => handle,
         Ok(None) => {
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ No tienes una cuenta de Codeforces vinculada. Usa `!account tu_handle` primero.",
-                )
-                .await;
-            return Ok(());
+            return Reply::text(ctx.tr_args(
+                "common.no_linked_account",
+                &[("prefix", &ctx.settings.prefix)],
+            ));
         }
         Err(e) => {
             eprintln!("Database error getting user handle: {}", e);
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ Error al acceder a la base de datos. Intenta de nuevo más tarde.",
-                )
-                .await;
-            return Ok(());
+            return Reply::text(ctx.tr("common.database_error"));
         }
     };
 
@@ -121,35 +152,21 @@ pub async fn execute(
     match sqlx::query_scalar::<_, bool>(
         "SELECT EXISTS(SELECT 1 FROM user_solved_problem WHERE guild_id = $1 AND user_id = $2 AND problem_id = $3)"
     )
-    .bind(guild.id.get() as i64)
-    .bind(msg.author.id.get() as i64)
-    .bind(problem_id)
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
+    .bind(problem_id.to_string())
     .fetch_one(db)
     .await
     {
         Ok(true) => {
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    &format!("ℹ️ El problema `{}` ya está marcado como resuelto.", problem_id),
-                )
-                .await;
-            return Ok(());
+            return Reply::text(ctx.tr_args("solved.already_solved", &[("problem", &problem_id)]));
         }
         Ok(false) => {
             // Continue with verification
         }
         Err(e) => {
             eprintln!("Database error checking solved status: {}", e);
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ Error al verificar el estado del problema. Intenta de nuevo más tarde.",
-                )
-                .await;
-            return Ok(());
+            return Reply::text(ctx.tr("solved.check_error"));
         }
     }
 
@@ -158,127 +175,22 @@ pub async fn execute(
     // =====================
 
     // Get user submissions from Codeforces API
-    match api::codeforces::get_user_status(&user_handle).await {
-        Ok(user_status) => {
-            // Check if API returned successful response
-            if user_status.status != "OK" {
-                let _ = msg
-                    .channel_id
-                    .say(
-                        http,
-                        "❌ Error al obtener las submissions de Codeforces. Intenta más tarde.",
-                    )
-                    .await;
-                return Ok(());
-            }
-
+    let submissions = match ctx
+        .bot
+        .codeforces
+        .get_user_status(&user_handle, None, None)
+        .await
+    {
+        Ok(submissions) => submissions,
+        Err(CodeforcesError::NotFound { .. }) => {
             // =====================
-            //   Search for Solved Submission
+            //   Linked Handle No Longer Exists
             // =====================
 
-            // Look for a successful submission of the specific problem
-            let is_solved = user_status.result.iter().any(|submission| {
-                // Check if this submission matches our problem
-                submission.problem.contestId == Some(contest_id)
-                    && submission.problem.index.to_uppercase() == index.to_uppercase()
-                    && submission.verdict.as_deref() == Some("OK")
-            });
-
-            if is_solved {
-                // =====================
-                //   Mark Problem as Solved
-                // =====================
-
-                // Insert the solved problem into database
-                match sqlx::query(
-                    "INSERT INTO user_solved_problem (guild_id, user_id, problem_id) VALUES ($1, $2, $3)"
-                )
-                .bind(guild.id.get() as i64)
-                .bind(msg.author.id.get() as i64)
-                .bind(problem_id)
-                .execute(db)
-                .await
-                {
-                    Ok(_) => {
-                        // Award 1 coin for solving the problem
-                        match sqlx::query(
-                            "UPDATE user_info SET coins = coins + 1 WHERE guild_id = $1 AND user_id = $2"
-                        )
-                        .bind(guild.id.get() as i64)
-                        .bind(msg.author.id.get() as i64)
-                        .execute(db)
-                        .await
-                        {
-                            Ok(_) => {
-                                let _ = msg
-                                    .channel_id
-                                    .say(
-                                        http,
-                                        &format!(
-                                            "🎉 **¡Problema resuelto verificado!**\n\n\
-                                            ✅ El problema `{}` ha sido marcado como resuelto.\n\
-                                            👤 Usuario: `{}`\n\
-                                            💰 **+1 moneda ganada!**\n\
-                                            🏆 ¡Felicitaciones por la solución exitosa!",
-                                            problem_id, user_handle
-                                        ),
-                                    )
-                                    .await;
-                            }
-                            Err(e) => {
-                                eprintln!("Database error updating coins: {}", e);
-                                // Still notify about solved problem, even if coins failed
-                                let _ = msg
-                                    .channel_id
-                                    .say(
-                                        http,
-                                        &format!(
-                                            "🎉 **¡Problema resuelto verificado!**\n\n\
-                                            ✅ El problema `{}` ha sido marcado como resuelto.\n\
-                                            👤 Usuario: `{}`\n\
-                                            ⚠️ Hubo un error al otorgar la moneda, pero tu progreso fue guardado.\n\
-                                            🏆 ¡Felicitaciones por la solución exitosa!",
-                                            problem_id, user_handle
-                                        ),
-                                    )
-                                    .await;
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Database error inserting solved problem: {}", e);
-                        let _ = msg
-                            .channel_id
-                            .say(
-                                http,
-                                "❌ Error al marcar el problema como resuelto. Intenta de nuevo más tarde.",
-                            )
-                            .await;
-                    }
-                }
-            } else {
-                // =====================
-                //   Problem Not Solved Response
-                // =====================
-
-                let _ = msg
-                    .channel_id
-                    .say(
-                        http,
-                        &format!(
-                            "❌ **Problema no resuelto**\n\n\
-                            🔍 No se encontró una solución exitosa para el problema `{}`\n\
-                            👤 Usuario: `{}`\n\n\
-                            💡 **Posibles razones:**\n\
-                            • El problema no ha sido resuelto aún\n\
-                            • La solución no pasó todos los test cases\n\
-                            • El problema no existe o el formato es incorrecto\n\n\
-                            ¡Sigue intentando! 💪",
-                            problem_id, user_handle
-                        ),
-                    )
-                    .await;
-            }
+            return Reply::text(ctx.tr_args(
+                "solved.handle_gone",
+                &[("handle", &user_handle), ("prefix", &ctx.settings.prefix)],
+            ));
         }
         Err(e) => {
             // =====================
@@ -286,15 +198,153 @@ pub async fn execute(
             // =====================
 
             eprintln!("Codeforces API error in solved command: {}", e);
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ Error al conectar con la API de Codeforces. Verifica que tu handle sea correcto e intenta más tarde.",
-                )
-                .await;
+            return Reply::text(api_error_message(ctx.language(), &e));
+        }
+    };
+
+    // =====================
+    //   Search for Solved Submission
+    // =====================
+
+    let accepted = first_accepted(&submissions, &problem_id);
+
+    let Some(accepted) = accepted else {
+        // =====================
+        //   Problem Not Solved Response
+        // =====================
+
+        return Reply::text(ctx.tr_args(
+            "solved.not_solved",
+            &[("problem", &problem_id), ("handle", &user_handle)],
+        ));
+    };
+
+    // =====================
+    //   Mark Problem as Solved
+    // =====================
+
+    // Solving a daily problem before the next one was posted earns a bonus on top
+    let bonus = match daily_bonus(
+        db,
+        guild_id.get() as i64,
+        &problem_id,
+        accepted.creation_time_seconds,
+    )
+    .await
+    {
+        Ok(bonus) => bonus,
+        Err(e) => {
+            eprintln!("Database error checking daily bonus: {}", e);
+            0
+        }
+    };
+
+    // Store the solve and award its coins together
+    match record_solve(
+        db,
+        guild_id.get() as i64,
+        ctx.author.id.get() as i64,
+        &problem_id,
+        SOLVE_REWARD + bonus,
+    )
+    .await
+    {
+        Ok(true) => {
+            let verified = ctx.tr_args(
+                "solved.verified",
+                &[("problem", &problem_id), ("handle", &user_handle)],
+            );
+
+            if bonus > 0 {
+                Reply::text(format!(
+                    "{}\n{}",
+                    verified,
+                    ctx.tr_args("solved.daily_bonus", &[("coins", &bonus)])
+                ))
+            } else {
+                Reply::text(verified)
+            }
+        }
+        // Solve detection recorded it since the check above
+        Ok(false) => Reply::text(ctx.tr_args("solved.already_solved", &[("problem", &problem_id)])),
+        Err(e) => {
+            eprintln!("Database error saving solved problem: {}", e);
+            Reply::text(ctx.tr("solved.save_error"))
         }
     }
+}
+
+// Insert the solved problem and add its coins in one transaction.
+// Returns false when the problem was already recorded, awarding nothing.
+async fn record_solve(
+    db: &PgPool,
+    guild_id: i64,
+    user_id: i64,
+    problem_id: &ProblemId,
+    coins: i64,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let inserted = sqlx::query(
+        "INSERT INTO user_solved_problem (guild_id, user_id, problem_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT DO NOTHING",
+    )
+    .bind(guild_id)
+    .bind(user_id)
+    .bind(problem_id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
+    if inserted.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    sqlx::query("UPDATE user_info SET coins = coins + $3 WHERE guild_id = $1 AND user_id = $2")
+        .bind(guild_id)
+        .bind(user_id)
+        .bind(coins)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
 
-    Ok(())
+    Ok(true)
+}
+
+// The first accepted submission of the problem, i.e. when it was actually solved
+// (`submissions` is newest first, as returned by user.status)
+fn first_accepted<'a>(
+    submissions: &'a [Submission],
+    problem_id: &ProblemId,
+) -> Option<&'a Submission> {
+    submissions
+        .iter()
+        .rev()
+        .find(|submission| problem_id.matches(&submission.problem) && submission.is_accepted())
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::{problem, submission};
+
+    #[test]
+    fn picks_the_oldest_accepted_submission() {
+        // Newest first, as returned by user.status
+        let submissions = [
+            submission(4, problem(467, "B"), "OK", 1_700_090_000),
+            submission(3, problem(4, "A"), "OK", 1_700_050_000),
+            submission(2, problem(467, "B"), "OK", 1_700_010_000),
+            submission(1, problem(467, "B"), "WRONG_ANSWER", 1_700_000_000),
+        ];
+
+        let accepted = first_accepted(&submissions, &"467B".parse().unwrap()).unwrap();
+        assert_eq!(accepted.id, 2);
+        assert!(first_accepted(&submissions, &"4B".parse().unwrap()).is_none());
+    }
 }
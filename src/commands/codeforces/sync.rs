@@ -0,0 +1,226 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use super::api_error_message;
+use super::solved::SOLVE_REWARD;
+use crate::api::codeforces::{CodeforcesError, Submission};
+use crate::commands::context::{CommandContext, Reply};
+use crate::Bot;
+use serenity::builder::CreateCommand;
+use serenity::model::id::{GuildId, UserId};
+use std::collections::BTreeSet;
+use std::fmt;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /sync slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("sync")
+}
+
+// =====================
+//   Sync Command Implementation
+// =====================
+
+// Imports every problem the member's linked handle has solved (`!sync` and `/sync`)
+pub async fn execute(ctx: &CommandContext<'_>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    let handle = match sqlx::query_scalar::<_, Option<String>>(
+        "SELECT codeforces_handle FROM user_info WHERE guild_id = $1 AND user_id = $2",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(Some(Some(handle))) => handle,
+        Ok(_) => {
+            return Reply::text(ctx.tr_args(
+                "common.no_linked_account",
+                &[("prefix", &ctx.settings.prefix)],
+            ));
+        }
+        Err(e) => {
+            eprintln!("Database error getting user handle: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    Reply::text(import_message(ctx, guild_id, &handle).await)
+}
+
+// Run the import and describe its outcome; also used right after `!account` links a handle
+pub async fn import_message(ctx: &CommandContext<'_>, guild_id: GuildId, handle: &str) -> String {
+    match import_solves(ctx.bot, guild_id, ctx.author.id, handle).await {
+        Ok(summary) if summary.coins > 0 => ctx.tr_args(
+            "sync.imported_coins",
+            &[
+                ("handle", &handle),
+                ("imported", &summary.imported),
+                ("accepted", &summary.accepted),
+                ("coins", &summary.coins),
+            ],
+        ),
+        Ok(summary) => ctx.tr_args(
+            "sync.imported",
+            &[
+                ("handle", &handle),
+                ("imported", &summary.imported),
+                ("accepted", &summary.accepted),
+            ],
+        ),
+        Err(SyncError::Api(e)) => {
+            eprintln!("Codeforces API error importing solves: {}", e);
+            api_error_message(ctx.language(), &e)
+        }
+        Err(SyncError::Database(e)) => {
+            eprintln!("Database error importing solves: {}", e);
+            ctx.tr("sync.save_error").to_string()
+        }
+    }
+}
+
+// =====================
+//   Solve Import
+// =====================
+
+// Outcome of importing a handle's history
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SyncSummary {
+    // Distinct problems the handle has an accepted submission for
+    pub accepted: usize,
+    // Problems that weren't recorded for the member yet
+    pub imported: u64,
+    // Coins awarded for the imported problems (0 unless the guild enabled it)
+    pub coins: i64,
+}
+
+// Why an import failed
+#[derive(Debug)]
+pub enum SyncError {
+    Api(CodeforcesError),
+    Database(sqlx::Error),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncError::Api(e) => write!(f, "Codeforces API error: {}", e),
+            SyncError::Database(e) => write!(f, "database error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SyncError {}
+
+impl From<CodeforcesError> for SyncError {
+    fn from(e: CodeforcesError) -> Self {
+        SyncError::Api(e)
+    }
+}
+
+impl From<sqlx::Error> for SyncError {
+    fn from(e: sqlx::Error) -> Self {
+        SyncError::Database(e)
+    }
+}
+
+// Record every accepted problem of the handle as solved by the member, in one transaction.
+// Whether these historical solves award coins is the guild's `sync_awards_coins` setting.
+pub async fn import_solves(
+    bot: &Bot,
+    guild_id: GuildId,
+    user_id: UserId,
+    handle: &str,
+) -> Result<SyncSummary, SyncError> {
+    // The whole history, newest first
+    let submissions = bot.codeforces.get_user_status(handle, None, None).await?;
+    let problem_ids: Vec<String> = accepted_problem_ids(&submissions).into_iter().collect();
+
+    let mut tx = bot.db.begin().await?;
+
+    // Problems already recorded (through `!solved` or solve detection) are skipped
+    let imported = sqlx::query(
+        "INSERT INTO user_solved_problem (guild_id, user_id, problem_id)
+        SELECT $1, $2, UNNEST($3::TEXT[])
+        ON CONFLICT DO NOTHING",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(user_id.get() as i64)
+    .bind(&problem_ids)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let awards_coins = sqlx::query_scalar::<_, Option<bool>>(
+        "SELECT sync_awards_coins FROM guild_config WHERE guild_id = $1",
+    )
+    .bind(guild_id.get() as i64)
+    .fetch_optional(&mut *tx)
+    .await?
+    .flatten()
+    .unwrap_or(false);
+
+    let coins = if awards_coins {
+        imported as i64 * SOLVE_REWARD
+    } else {
+        0
+    };
+
+    if coins > 0 {
+        sqlx::query("UPDATE user_info SET coins = coins + $3 WHERE guild_id = $1 AND user_id = $2")
+            .bind(guild_id.get() as i64)
+            .bind(user_id.get() as i64)
+            .bind(coins)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(SyncSummary {
+        accepted: problem_ids.len(),
+        imported,
+        coins,
+    })
+}
+
+// Distinct ids of the problems with an accepted submission
+fn accepted_problem_ids(submissions: &[Submission]) -> BTreeSet<String> {
+    submissions
+        .iter()
+        .filter(|submission| submission.is_accepted())
+        .filter_map(|submission| submission.problem.id())
+        .map(|problem_id| problem_id.to_string())
+        .collect()
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::{problem, submission};
+
+    #[test]
+    fn collects_each_accepted_problem_once() {
+        let submissions = [
+            submission(5, problem(467, "B"), "OK", 1_700_000_005),
+            submission(4, problem(4, "A"), "WRONG_ANSWER", 1_700_000_004),
+            submission(3, problem(467, "B"), "OK", 1_700_000_003),
+            submission(2, problem(1850, "A1"), "OK", 1_700_000_002),
+            submission(1, problem(4, "A"), "COMPILATION_ERROR", 1_700_000_001),
+        ];
+
+        let ids: Vec<String> = accepted_problem_ids(&submissions).into_iter().collect();
+        assert_eq!(ids, ["1850A1", "467B"]);
+    }
+}
@@ -0,0 +1,192 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::api::codeforces::{Problem, Submission};
+use crate::api::problem_id::ProblemId;
+use chrono::{DateTime, Utc};
+use rand::seq::IndexedRandom;
+use serenity::model::id::{GuildId, UserId};
+use sqlx::PgPool;
+
+// How long a member has to submit the compilation error
+pub const VERIFICATION_MINUTES: i32 = 10;
+
+// Newest submissions searched for the compilation error
+pub const RECENT_SUBMISSIONS: u32 = 20;
+
+// Tolerated difference between our clock and Codeforces' submission times
+const CLOCK_SKEW_SECONDS: i64 = 60;
+
+// =====================
+//   Pending Verification
+// =====================
+
+// Handle a member asked to link, waiting for proof that they own it
+#[derive(Debug, Clone)]
+pub struct PendingVerification {
+    pub handle: String,
+    // Problem the compilation error must be submitted to
+    pub problem_id: ProblemId,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingVerification {
+    // Whether a recent compilation error to the chosen problem proves ownership of the handle
+    pub fn is_proven_by(&self, submissions: &[Submission]) -> bool {
+        let started = self.created_at.timestamp() - CLOCK_SKEW_SECONDS;
+
+        submissions.iter().any(|submission| {
+            self.problem_id.matches(&submission.problem)
+                && submission.is_compilation_error()
+                && submission.creation_time_seconds >= started
+        })
+    }
+}
+
+// =====================
+//   Verification Storage
+// =====================
+
+// The member's verification, unless there is none or it expired
+pub async fn pending(
+    db: &PgPool,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<Option<PendingVerification>, sqlx::Error> {
+    let row = sqlx::query_as::<_, (String, String, DateTime<Utc>, DateTime<Utc>)>(
+        "SELECT codeforces_handle, problem_id, created_at, expires_at
+        FROM pending_verification
+        WHERE guild_id = $1 AND user_id = $2 AND expires_at > NOW()",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(user_id.get() as i64)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(
+        row.and_then(|(handle, problem_id, created_at, expires_at)| {
+            Some(PendingVerification {
+                handle,
+                problem_id: problem_id.parse().ok()?,
+                created_at,
+                expires_at,
+            })
+        }),
+    )
+}
+
+// Start (or restart) the member's verification of a handle
+pub async fn start(
+    db: &PgPool,
+    guild_id: GuildId,
+    user_id: UserId,
+    handle: &str,
+    problem_id: &ProblemId,
+) -> Result<PendingVerification, sqlx::Error> {
+    // Expired verifications are only kept until someone starts a new one
+    sqlx::query("DELETE FROM pending_verification WHERE expires_at <= NOW()")
+        .execute(db)
+        .await?;
+
+    let (created_at, expires_at) = sqlx::query_as::<_, (DateTime<Utc>, DateTime<Utc>)>(
+        "INSERT INTO pending_verification
+            (guild_id, user_id, codeforces_handle, problem_id, created_at, expires_at)
+        VALUES ($1, $2, $3, $4, NOW(), NOW() + make_interval(mins => $5))
+        ON CONFLICT (guild_id, user_id)
+        DO UPDATE SET
+            codeforces_handle = EXCLUDED.codeforces_handle,
+            problem_id = EXCLUDED.problem_id,
+            created_at = EXCLUDED.created_at,
+            expires_at = EXCLUDED.expires_at
+        RETURNING created_at, expires_at",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(user_id.get() as i64)
+    .bind(handle)
+    .bind(problem_id.to_string())
+    .bind(VERIFICATION_MINUTES)
+    .fetch_one(db)
+    .await?;
+
+    Ok(PendingVerification {
+        handle: handle.to_string(),
+        problem_id: problem_id.clone(),
+        created_at,
+        expires_at,
+    })
+}
+
+// =====================
+//   Problem Selection
+// =====================
+
+// Random problemset problem to submit the compilation error to.
+// Gym problems are skipped because submitting to them needs joining the gym first,
+// and QUESTION problems because their answers are never compiled.
+pub fn pick_problem(problems: &[Problem]) -> Option<ProblemId> {
+    let candidates: Vec<ProblemId> = problems
+        .iter()
+        .filter(|problem| problem.problem_type == "PROGRAMMING")
+        .filter_map(Problem::id)
+        .filter(|id| !id.is_gym())
+        .collect();
+
+    candidates.choose(&mut rand::rng()).cloned()
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::problem;
+    use chrono::TimeZone;
+
+    // Submission to a problem of contest 4 with the given verdict and time
+    fn submission(index: &str, verdict: &str, creation_time_seconds: i64) -> Submission {
+        crate::api::codeforces::builders::submission(
+            1,
+            problem(4, index),
+            verdict,
+            creation_time_seconds,
+        )
+    }
+
+    #[test]
+    fn only_a_new_compilation_error_to_the_problem_proves_ownership() {
+        let created_at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let verification = PendingVerification {
+            handle: "someone".to_string(),
+            problem_id: "4A".parse().unwrap(),
+            created_at,
+            expires_at: created_at,
+        };
+        let now = created_at.timestamp();
+
+        assert!(verification.is_proven_by(&[submission("A", "COMPILATION_ERROR", now + 30)]));
+
+        // Wrong verdict, wrong problem, or submitted before the verification started
+        assert!(!verification.is_proven_by(&[submission("A", "OK", now + 30)]));
+        assert!(!verification.is_proven_by(&[submission("B", "COMPILATION_ERROR", now + 30)]));
+        assert!(!verification.is_proven_by(&[submission("A", "COMPILATION_ERROR", now - 3600)]));
+    }
+
+    #[test]
+    fn picks_only_programming_problems_outside_the_gym() {
+        let question = Problem {
+            problem_type: "QUESTION".to_string(),
+            ..problem(1145, "A")
+        };
+        let gym = problem(100001, "A");
+
+        assert_eq!(pick_problem(&[question.clone(), gym.clone()]), None);
+        assert_eq!(
+            pick_problem(&[question, gym, problem(4, "A")]),
+            Some("4A".parse().unwrap())
+        );
+    }
+}
@@ -0,0 +1,233 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::registry::CommandSpec;
+use crate::i18n::{self, Language};
+use crate::settings::GuildSettings;
+use crate::Bot;
+use serenity::builder::{CreateEmbed, CreateMessage, EditInteractionResponse};
+use serenity::cache::Cache;
+use serenity::http::Http;
+use serenity::model::application::{CommandInteraction, ResolvedOption, ResolvedValue};
+use serenity::model::channel::Message;
+use serenity::model::id::{ChannelId, GuildId, RoleId, UserId};
+use serenity::model::permissions::Permissions;
+use serenity::model::user::User;
+use std::sync::Arc;
+
+// =====================
+//   Command Context
+// =====================
+
+// Everything a command needs to know about who invoked it and where.
+// Built from either a prefix message or a slash command interaction.
+pub struct CommandContext<'a> {
+    pub bot: &'a Bot,
+    pub http: &'a Arc<Http>,
+    pub cache: &'a Arc<Cache>,
+    pub guild_id: Option<GuildId>,
+    pub author: &'a User,
+    // Author's roles in the guild (empty in DMs)
+    pub roles: Vec<RoleId>,
+    // Guild permissions when Discord already resolved them (slash commands)
+    pub resolved_permissions: Option<Permissions>,
+    // Command being run and the settings of the guild it runs in
+    pub command: &'static CommandSpec,
+    pub settings: GuildSettings,
+}
+
+impl<'a> CommandContext<'a> {
+    // Context for a prefix command (`!problem 800 1200`)
+    pub fn from_message(
+        bot: &'a Bot,
+        http: &'a Arc<Http>,
+        cache: &'a Arc<Cache>,
+        msg: &'a Message,
+        command: &'static CommandSpec,
+        settings: GuildSettings,
+    ) -> Self {
+        Self {
+            bot,
+            http,
+            cache,
+            guild_id: msg.guild_id,
+            author: &msg.author,
+            roles: msg
+                .member
+                .as_ref()
+                .map(|member| member.roles.clone())
+                .unwrap_or_default(),
+            resolved_permissions: None,
+            command,
+            settings,
+        }
+    }
+
+    // Context for a slash command (`/problem min_rating:800 max_rating:1200`)
+    pub fn from_interaction(
+        bot: &'a Bot,
+        http: &'a Arc<Http>,
+        cache: &'a Arc<Cache>,
+        interaction: &'a CommandInteraction,
+        command: &'static CommandSpec,
+        settings: GuildSettings,
+    ) -> Self {
+        let member = interaction.member.as_deref();
+
+        Self {
+            bot,
+            http,
+            cache,
+            guild_id: interaction.guild_id,
+            author: &interaction.user,
+            roles: member
+                .map(|member| member.roles.clone())
+                .unwrap_or_default(),
+            resolved_permissions: member.and_then(|member| member.permissions),
+            command,
+            settings,
+        }
+    }
+
+    // Prefix usage of the running command (`!problem MIN_RATING MAX_RATING`)
+    pub fn usage(&self) -> String {
+        self.command.usage_line(&self.settings.prefix)
+    }
+
+    // Language the guild chose for replies
+    pub fn language(&self) -> Language {
+        self.settings.language
+    }
+
+    // Message in the guild's language
+    pub fn tr(&self, key: &str) -> &'static str {
+        i18n::tr(self.settings.language, key)
+    }
+
+    // Message in the guild's language with its placeholders filled in
+    pub fn tr_args(&self, key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        i18n::tr_args(self.settings.language, key, args)
+    }
+}
+
+// =====================
+//   Command Reply
+// =====================
+
+// What a command answers with; sent as a message or as the interaction response
+#[derive(Default)]
+pub struct Reply {
+    pub content: Option<String>,
+    pub embed: Option<CreateEmbed>,
+}
+
+impl Reply {
+    // Plain text reply
+    pub fn text(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            embed: None,
+        }
+    }
+
+    // Embed-only reply
+    pub fn embed(embed: CreateEmbed) -> Self {
+        Self {
+            content: None,
+            embed: Some(embed),
+        }
+    }
+
+    // Add text shown above the embed
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    // Build the channel message for prefix commands
+    pub fn into_message(self) -> CreateMessage {
+        let mut message = CreateMessage::new();
+        if let Some(content) = self.content {
+            message = message.content(content);
+        }
+        if let Some(embed) = self.embed {
+            message = message.embed(embed);
+        }
+        message
+    }
+
+    // Build the edit of a deferred interaction response for slash commands
+    pub fn into_interaction_response(self) -> EditInteractionResponse {
+        let mut response = EditInteractionResponse::new();
+        if let Some(content) = self.content {
+            response = response.content(content);
+        }
+        if let Some(embed) = self.embed {
+            response = response.embed(embed);
+        }
+        response
+    }
+}
+
+// =====================
+//   Slash Option Helpers
+// =====================
+
+// Find a string option by name
+pub fn string_option<'a>(options: &[ResolvedOption<'a>], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::String(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+// Find an integer option by name
+pub fn integer_option(options: &[ResolvedOption<'_>], name: &str) -> Option<i64> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::Integer(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+// Find a boolean option by name
+pub fn boolean_option(options: &[ResolvedOption<'_>], name: &str) -> Option<bool> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::Boolean(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+// Find a user option by name
+pub fn user_option<'a>(options: &[ResolvedOption<'a>], name: &str) -> Option<&'a User> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::User(user, _) if option.name == name => Some(user),
+        _ => None,
+    })
+}
+
+// Find a channel option by name
+pub fn channel_option(options: &[ResolvedOption<'_>], name: &str) -> Option<ChannelId> {
+    options.iter().find_map(|option| match &option.value {
+        ResolvedValue::Channel(channel) if option.name == name => Some(channel.id),
+        _ => None,
+    })
+}
+
+// =====================
+//   Prefix Argument Helpers
+// =====================
+
+// First member mentioned in prefix arguments (`<@123>` or `<@!123>`)
+pub fn mentioned_user(args: &str) -> Option<UserId> {
+    args.split_whitespace()
+        .find_map(|word| {
+            word.strip_prefix("<@")?
+                .strip_suffix('>')?
+                .trim_start_matches('!')
+                .parse::<u64>()
+                .ok()
+        })
+        .filter(|id| *id != 0)
+        .map(UserId::new)
+}
@@ -2,24 +2,28 @@
 //   Imports and Dependencies
 // =====================
 
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use serenity::model::guild::PartialGuild;
-use sqlx::PgPool;
-use std::sync::Arc;
+use crate::commands::context::{CommandContext, Reply};
+use serenity::builder::CreateCommand;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /balance slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("balance")
+}
 
 // =====================
 //   Balance Command Implementation
 // =====================
 
-// Main function to handle the !balance command
-// Shows the user's current coin balance
-pub async fn execute(
-    http: &Arc<Http>,
-    msg: &Message,
-    db: &PgPool,
-    guild: &PartialGuild,
-) -> Result<(), serenity::Error> {
+// Shows the user's current coin balance (`!balance` and `/balance`)
+pub async fn execute(ctx: &CommandContext<'_>) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
     // =====================
     //   Query User Balance
     // =====================
@@ -28,38 +32,27 @@ pub async fn execute(
     match sqlx::query_scalar::<_, Option<i64>>(
         "SELECT coins FROM user_info WHERE guild_id = $1 AND user_id = $2",
     )
-    .bind(guild.id.get() as i64)
-    .bind(msg.author.id.get() as i64)
-    .fetch_optional(db)
+    .bind(guild_id.get() as i64)
+    .bind(ctx.author.id.get() as i64)
+    .fetch_optional(&ctx.bot.db)
     .await
     {
-        Ok(Some(Some(coins))) => {
-            // =====================
-            //   Display Balance
-            // =====================
-
-            let response = format!(
-                "💰 **Balance de monedas**\n\n\
-                👤 Usuario: <@{}>\n\
-                🪙 **Monedas:** `{}`\n\n\
-                💡 *Resuelve problemas de Codeforces con `!solved` para ganar más monedas!*",
-                msg.author.id, coins
-            );
-
-            let _ = msg.channel_id.say(http, &response).await;
-        }
+        // =====================
+        //   Display Balance
+        // =====================
+        Ok(Some(Some(coins))) => Reply::text(ctx.tr_args(
+            "balance.summary",
+            &[
+                ("user", &ctx.author.id),
+                ("coins", &coins),
+                ("prefix", &ctx.settings.prefix),
+            ],
+        )),
+        // =====================
+        //   User Not Found Response
+        // =====================
         Ok(Some(None)) | Ok(None) => {
-            // =====================
-            //   User Not Found Response
-            // =====================
-
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ No tienes un perfil en este servidor. Usa `!account tu_handle` para crear uno y comenzar a ganar monedas.",
-                )
-                .await;
+            Reply::text(ctx.tr_args("balance.no_profile", &[("prefix", &ctx.settings.prefix)]))
         }
         Err(e) => {
             // =====================
@@ -67,15 +60,7 @@ pub async fn execute(
             // =====================
 
             eprintln!("Database error in balance command: {}", e);
-            let _ = msg
-                .channel_id
-                .say(
-                    http,
-                    "❌ Error al acceder a la base de datos. Intenta de nuevo más tarde.",
-                )
-                .await;
+            Reply::text(ctx.tr("common.database_error"))
         }
     }
-
-    Ok(())
 }
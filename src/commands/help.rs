@@ -0,0 +1,182 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{string_option, CommandContext, Reply};
+use crate::commands::permissions;
+use crate::commands::registry::{self, CommandSpec, COMMANDS};
+use crate::Bot;
+use serenity::builder::{
+    CreateAutocompleteResponse, CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter,
+};
+use serenity::model::application::{AutocompleteOption, CommandOptionType, ResolvedOption};
+use serenity::model::permissions::Permissions;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /help slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("help").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "command",
+            "Command to show details for",
+        )
+        .set_autocomplete(true),
+    )
+}
+
+// Suggest command names while the user types
+pub async fn autocomplete(
+    _bot: &Bot,
+    option: &AutocompleteOption<'_>,
+) -> CreateAutocompleteResponse {
+    let typed = option.value.trim().to_lowercase();
+
+    COMMANDS
+        .iter()
+        .filter(|command| command.name.starts_with(&typed))
+        .take(25)
+        .fold(CreateAutocompleteResponse::new(), |response, command| {
+            response.add_string_choice(command.name, command.name)
+        })
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!help [command]`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    // Accept `!help !problem` as well as `!help problem`
+    run(ctx, args.trim_start_matches(ctx.settings.prefix.as_str())).await
+}
+
+// Handle `/help [command]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    run(
+        ctx,
+        string_option(options, "command").unwrap_or_default().trim(),
+    )
+    .await
+}
+
+// =====================
+//   Help Command Implementation
+// =====================
+
+// Lists the commands the caller can run, or details a single command
+async fn run(ctx: &CommandContext<'_>, name: &str) -> Reply {
+    // Without permissions we can still show everything everyone may run
+    let granted = match permissions::caller_permissions(ctx).await {
+        Ok(granted) => granted,
+        Err(e) => {
+            eprintln!("Error fetching guild permissions for help: {}", e);
+            Permissions::empty()
+        }
+    };
+
+    if name.is_empty() {
+        return Reply::embed(command_list(ctx, granted));
+    }
+
+    match registry::find(name) {
+        Some(command) => Reply::embed(command_details(ctx, command, granted)),
+        None => Reply::text(ctx.tr_args(
+            "help.unknown",
+            &[("command", &name), ("prefix", &ctx.settings.prefix)],
+        )),
+    }
+}
+
+// Embed listing every command the caller can run here
+fn command_list(ctx: &CommandContext<'_>, granted: Permissions) -> CreateEmbed {
+    let prefix = &ctx.settings.prefix;
+
+    let lines = COMMANDS
+        .iter()
+        .filter(|command| permissions::can_run(ctx, command, granted))
+        .map(|command| {
+            format!(
+                "`{}{}` - {}",
+                prefix,
+                command.name,
+                command.description(ctx.language())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CreateEmbed::new()
+        .title(ctx.tr("help.list_title"))
+        .description(lines)
+        .footer(CreateEmbedFooter::new(
+            ctx.tr_args("help.list_footer", &[("prefix", prefix)]),
+        ))
+}
+
+// Embed describing one command: usage, aliases, examples and who may run it
+fn command_details(
+    ctx: &CommandContext<'_>,
+    command: &CommandSpec,
+    granted: Permissions,
+) -> CreateEmbed {
+    let prefix = &ctx.settings.prefix;
+
+    let mut embed = CreateEmbed::new()
+        .title(format!("📖 {}{}", prefix, command.name))
+        .description(command.description(ctx.language()))
+        .field(
+            ctx.tr("help.usage"),
+            format!("`{}`", command.usage_line(prefix)),
+            false,
+        );
+
+    if !command.aliases.is_empty() {
+        let aliases = command
+            .aliases
+            .iter()
+            .map(|alias| format!("`{}{}`", prefix, alias))
+            .collect::<Vec<_>>()
+            .join(", ");
+        embed = embed.field(ctx.tr("help.aliases"), aliases, false);
+    }
+
+    if !command.examples.is_empty() {
+        let examples = command
+            .examples
+            .iter()
+            .map(|example| {
+                let line = format!("{}{} {}", prefix, command.name, example);
+                format!("`{}`", line.trim_end())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field(ctx.tr("help.examples"), examples, false);
+    }
+
+    // Who may run it
+    let mut requirements = Vec::new();
+    if command.permissions.is_empty() {
+        requirements.push(ctx.tr("help.everyone").to_string());
+    } else {
+        let names = command.permissions.get_permission_names().join(", ");
+        requirements.push(match permissions::admin_role_for(ctx, command) {
+            Some(role_id) => ctx.tr_args(
+                "help.permission_or_role",
+                &[("permissions", &names), ("role", &role_id)],
+            ),
+            None => names,
+        });
+    }
+    if command.guild_only {
+        requirements.push(ctx.tr("help.servers_only").to_string());
+    }
+    if !permissions::can_run(ctx, command, granted) {
+        requirements.push(ctx.tr("help.not_allowed").to_string());
+    }
+
+    embed.field(ctx.tr("help.permissions"), requirements.join("\n"), false)
+}
@@ -2,7 +2,27 @@
 //   Commands Module
 // =====================
 
+use serenity::builder::CreateCommand;
+
 // Module declarations for bot command functionality
+pub mod admin; // Server administration commands (configuration, bot admin role)
 pub mod codeforces; // Codeforces-related commands (problem fetching, configuration)
+pub mod context; // Shared invocation context and replies for prefix and slash commands
 pub mod economy; // Economy-related commands (balance, transactions)
+pub mod help; // Help output generated from the command registry
+pub mod permissions; // Permission and DM checks run before every command
 pub mod ping; // Simple ping/pong command for connectivity testing
+pub mod registry; // Command registry used by the prefix and slash dispatchers
+pub mod stats; // Statistics commands (leaderboards, profiles)
+
+// =====================
+//   Slash Command Definitions
+// =====================
+
+// Every command registered with Discord as a slash command
+pub fn slash_commands() -> Vec<CreateCommand> {
+    registry::COMMANDS
+        .iter()
+        .map(registry::CommandSpec::slash_command)
+        .collect()
+}
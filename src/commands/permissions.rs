@@ -0,0 +1,248 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::CommandContext;
+use crate::commands::registry::CommandSpec;
+use serenity::model::guild::Role;
+use serenity::model::id::{GuildId, RoleId, UserId};
+use serenity::model::permissions::Permissions;
+use std::collections::HashMap;
+
+// =====================
+//   Permission Checks
+// =====================
+
+// Check that the command in the context may run, or return the reason it can't
+pub async fn check(ctx: &CommandContext<'_>) -> Result<(), String> {
+    let command = ctx.command;
+
+    if ctx.guild_id.is_none() {
+        // Server-only commands are refused outside of a guild
+        return if command.guild_only {
+            Err(ctx.tr("common.guild_only").to_string())
+        } else {
+            Ok(())
+        };
+    }
+
+    if command.permissions.is_empty() {
+        return Ok(());
+    }
+
+    let granted = match caller_permissions(ctx).await {
+        Ok(granted) => granted,
+        Err(e) => {
+            eprintln!("Error fetching guild for permission check: {}", e);
+            return Err(ctx.tr("permissions.check_failed").to_string());
+        }
+    };
+
+    if can_run(ctx, command, granted) {
+        return Ok(());
+    }
+
+    // Answer denied attempts instead of ignoring them
+    let required = command.permissions.get_permission_names().join(", ");
+    let response = match admin_role_for(ctx, command) {
+        Some(role_id) => ctx.tr_args(
+            "permissions.denied_or_role",
+            &[
+                ("permissions", &required),
+                ("role", &role_id),
+                ("command", &command.name),
+            ],
+        ),
+        None => ctx.tr_args(
+            "permissions.denied",
+            &[("permissions", &required), ("command", &command.name)],
+        ),
+    };
+
+    Err(response)
+}
+
+// Whether the caller may run a command, given their guild permissions
+pub fn can_run(ctx: &CommandContext<'_>, command: &CommandSpec, granted: Permissions) -> bool {
+    if ctx.guild_id.is_none() {
+        return !command.guild_only;
+    }
+
+    command.permissions.is_empty()
+        || is_authorized(
+            granted,
+            &ctx.roles,
+            command.permissions,
+            ctx.settings.admin_role_id,
+            command.delegable,
+        )
+}
+
+// Bot admin role that may run the command in place of its permissions, if any
+pub fn admin_role_for(ctx: &CommandContext<'_>, command: &CommandSpec) -> Option<RoleId> {
+    ctx.settings.admin_role_id.filter(|_| command.delegable)
+}
+
+// Guild permissions of the caller: resolved by Discord for slash commands,
+// computed from the guild's roles for prefix commands (empty in DMs)
+pub async fn caller_permissions(ctx: &CommandContext<'_>) -> Result<Permissions, serenity::Error> {
+    if let Some(granted) = ctx.resolved_permissions {
+        return Ok(granted);
+    }
+
+    let Some(guild_id) = ctx.guild_id else {
+        return Ok(Permissions::empty());
+    };
+
+    // Guild messages carry the author's roles, but not the permissions those roles grant.
+    // The gateway cache has the guild's roles; Discord is only asked on a cache miss.
+    if let Some(guild) = ctx.cache.guild(guild_id) {
+        return Ok(guild_permissions(
+            guild.id,
+            guild.owner_id,
+            &guild.roles,
+            ctx.author.id,
+            &ctx.roles,
+        ));
+    }
+
+    let guild = ctx.http.get_guild(guild_id).await?;
+    Ok(guild_permissions(
+        guild.id,
+        guild.owner_id,
+        &guild.roles,
+        ctx.author.id,
+        &ctx.roles,
+    ))
+}
+
+// A member may run a command with the required permissions, as an administrator,
+// or by holding the guild's bot admin role when the command can be delegated to it
+pub fn is_authorized(
+    granted: Permissions,
+    roles: &[RoleId],
+    required: Permissions,
+    admin_role_id: Option<RoleId>,
+    delegable: bool,
+) -> bool {
+    granted.administrator()
+        || granted.contains(required)
+        || delegable && admin_role_id.is_some_and(|role_id| roles.contains(&role_id))
+}
+
+// Guild-wide permissions of a member: the owner has all of them, everyone else the
+// union of @everyone and their roles (channel overwrites don't affect guild permissions)
+fn guild_permissions(
+    guild_id: GuildId,
+    owner_id: UserId,
+    guild_roles: &HashMap<RoleId, Role>,
+    user_id: UserId,
+    roles: &[RoleId],
+) -> Permissions {
+    if owner_id == user_id {
+        return Permissions::all();
+    }
+
+    // The @everyone role shares its id with the guild
+    let everyone = guild_roles
+        .get(&RoleId::new(guild_id.get()))
+        .map(|role| role.permissions)
+        .unwrap_or_default();
+
+    let granted = roles
+        .iter()
+        .filter_map(|role_id| guild_roles.get(role_id))
+        .fold(everyone, |granted, role| granted | role.permissions);
+
+    if granted.administrator() {
+        Permissions::all()
+    } else {
+        granted
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADMIN_ROLE: RoleId = RoleId::new(42);
+
+    #[test]
+    fn required_permissions_or_admin_role_authorize() {
+        let required = Permissions::MANAGE_GUILD;
+
+        assert!(is_authorized(required, &[], required, None, true));
+        assert!(is_authorized(
+            Permissions::ADMINISTRATOR,
+            &[],
+            required,
+            None,
+            true
+        ));
+        assert!(is_authorized(
+            Permissions::empty(),
+            &[ADMIN_ROLE],
+            required,
+            Some(ADMIN_ROLE),
+            true
+        ));
+    }
+
+    #[test]
+    fn admin_role_cannot_run_undelegable_commands() {
+        let required = Permissions::MANAGE_GUILD;
+
+        assert!(!is_authorized(
+            Permissions::empty(),
+            &[ADMIN_ROLE],
+            required,
+            Some(ADMIN_ROLE),
+            false
+        ));
+        assert!(is_authorized(
+            required,
+            &[ADMIN_ROLE],
+            required,
+            Some(ADMIN_ROLE),
+            false
+        ));
+        assert!(is_authorized(
+            Permissions::ADMINISTRATOR,
+            &[],
+            required,
+            Some(ADMIN_ROLE),
+            false
+        ));
+    }
+
+    #[test]
+    fn other_members_are_denied() {
+        let required = Permissions::MANAGE_GUILD;
+
+        assert!(!is_authorized(
+            Permissions::SEND_MESSAGES,
+            &[],
+            required,
+            None,
+            true
+        ));
+        assert!(!is_authorized(
+            Permissions::empty(),
+            &[RoleId::new(7)],
+            required,
+            Some(ADMIN_ROLE),
+            true
+        ));
+        assert!(!is_authorized(
+            Permissions::empty(),
+            &[ADMIN_ROLE],
+            required,
+            None,
+            true
+        ));
+    }
+}
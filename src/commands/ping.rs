@@ -2,22 +2,28 @@
 //   Imports and Dependencies
 // =====================
 
-use serenity::http::Http;
-use serenity::model::channel::Message;
-use std::sync::Arc;
+use crate::commands::context::{CommandContext, Reply};
+use serenity::builder::CreateCommand;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /ping slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("ping")
+}
 
 // =====================
 //   Ping Command Implementation
 // =====================
 
 // Simple ping command to test bot connectivity and response time
-// Responds with a "Pong!" message when user sends "!ping"
-pub async fn execute(http: &Arc<Http>, msg: &Message) -> Result<(), serenity::Error> {
+// Responds with a "Pong!" message to both "!ping" and "/ping"
+pub async fn execute(ctx: &CommandContext<'_>) -> Reply {
     // =====================
     //   Command Response
     // =====================
 
-    // Send pong response to the same channel where ping was received
-    msg.channel_id.say(http, "🏓 Pong!").await?;
-    Ok(())
+    Reply::text(ctx.tr("ping.pong"))
 }
@@ -0,0 +1,482 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::admin::{config, setadminrole, setlanguage};
+use crate::commands::codeforces::{
+    self, account, daily, problem, ratinghistory, setchannel, setdailybonus, sethour, setrating,
+    setsolvechannel, setsolvedthreshold, setsyncreward, solved, sync,
+};
+use crate::commands::context::{CommandContext, Reply};
+use crate::commands::economy::balance;
+use crate::commands::stats::{leaderboard, profile};
+use crate::commands::{help, ping};
+use crate::i18n::{self, Language};
+use crate::Bot;
+use serenity::builder::{CreateAutocompleteResponse, CreateCommand};
+use serenity::model::application::{AutocompleteOption, ResolvedOption};
+use serenity::model::permissions::Permissions;
+use std::future::Future;
+use std::pin::Pin;
+
+// =====================
+//   Handler Types
+// =====================
+
+// Future returned by every command handler
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// Runs a prefix command with the text that follows its name
+pub type PrefixHandler = for<'a> fn(&'a CommandContext<'a>, &'a str) -> BoxFuture<'a, Reply>;
+
+// Runs a slash command with its resolved options
+pub type SlashHandler =
+    for<'a> fn(&'a CommandContext<'a>, &'a [ResolvedOption<'a>]) -> BoxFuture<'a, Reply>;
+
+// Suggests values for the option being typed in a slash command
+pub type AutocompleteHandler =
+    for<'a> fn(&'a Bot, &'a AutocompleteOption<'a>) -> BoxFuture<'a, CreateAutocompleteResponse>;
+
+// =====================
+//   Command Specification
+// =====================
+
+// Everything the dispatchers and help output need to know about a command.
+// Descriptions live in the message catalog under `command.<name>`.
+pub struct CommandSpec {
+    // Name used after the prefix and as the slash command name
+    pub name: &'static str,
+    // Extra names accepted after the prefix
+    pub aliases: &'static [&'static str],
+    // Arguments, shown after the prefix and name (`MIN_RATING MAX_RATING`)
+    pub usage: &'static str,
+    // Sample arguments shown by help, also after the prefix and name
+    pub examples: &'static [&'static str],
+    // Permissions a member needs to run the command (empty for everyone);
+    // the guild's bot admin role is accepted in their place when `delegable`
+    pub permissions: Permissions,
+    // Whether the bot admin role may stand in for `permissions`. Off for commands
+    // that control the admin role itself, so holders can't hand it out.
+    pub delegable: bool,
+    // Whether the command is refused in DMs
+    pub guild_only: bool,
+    // Slash command definition registered with Discord
+    pub register: fn() -> CreateCommand,
+    pub prefix: PrefixHandler,
+    pub slash: SlashHandler,
+    pub autocomplete: Option<AutocompleteHandler>,
+}
+
+impl CommandSpec {
+    // Whether a prefix command name refers to this command
+    pub fn answers_to(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+
+    // Prefix usage with the given prefix (`!problem MIN_RATING MAX_RATING`)
+    pub fn usage_line(&self, prefix: &str) -> String {
+        format!("{}{} {}", prefix, self.name, self.usage)
+            .trim_end()
+            .to_string()
+    }
+
+    // Short description in the given language
+    pub fn description(&self, language: Language) -> &'static str {
+        i18n::tr(language, &format!("command.{}", self.name))
+    }
+
+    // Slash command definition with the catalog description, localized for the
+    // Discord clients of every supported language.
+    // Permissions are checked by the bot rather than through Discord's default member
+    // permissions, so members with the bot admin role can still see admin commands.
+    pub fn slash_command(&self) -> CreateCommand {
+        let command = (self.register)()
+            .description(self.description(Language::default()))
+            .dm_permission(!self.guild_only);
+
+        Language::ALL
+            .into_iter()
+            .fold(command, |command, language| {
+                language
+                    .discord_locales()
+                    .iter()
+                    .fold(command, |command, locale| {
+                        command.description_localized(*locale, self.description(language))
+                    })
+            })
+    }
+}
+
+// =====================
+//   Command Registry
+// =====================
+
+// Every command the bot understands, in the order help lists them
+pub static COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "help",
+        aliases: &[],
+        usage: "[command]",
+        examples: &["", "problem"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: false,
+        register: help::register,
+        prefix: |ctx, args| Box::pin(help::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(help::execute_slash(ctx, options)),
+        autocomplete: Some(|bot, option| Box::pin(help::autocomplete(bot, option))),
+    },
+    CommandSpec {
+        name: "ping",
+        aliases: &[],
+        usage: "",
+        examples: &[],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: false,
+        register: ping::register,
+        prefix: |ctx, _| Box::pin(ping::execute(ctx)),
+        slash: |ctx, _| Box::pin(ping::execute(ctx)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "problem",
+        aliases: &[],
+        usage: "[RATING | MIN MAX] [tag ...] [-tag ...] [unsolved]",
+        examples: &["", "1500", "1200 1600 dp -math", "greedy unsolved"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: false,
+        register: problem::register,
+        prefix: |ctx, args| Box::pin(problem::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(problem::execute_slash(ctx, options)),
+        autocomplete: Some(|bot, option| Box::pin(problem::autocomplete(bot, option))),
+    },
+    CommandSpec {
+        name: "daily",
+        aliases: &[],
+        usage: "",
+        examples: &[],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: daily::register,
+        prefix: |ctx, _| Box::pin(daily::execute(ctx)),
+        slash: |ctx, _| Box::pin(daily::execute(ctx)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "sethour",
+        aliases: &["sethora"],
+        usage: "HH:MM",
+        examples: &["09:00", "21:30"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: sethour::register,
+        prefix: |ctx, args| Box::pin(sethour::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(sethour::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setrating",
+        aliases: &[],
+        usage: "MIN_RATING MAX_RATING",
+        examples: &["", "800 1400"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setrating::register,
+        prefix: |ctx, args| Box::pin(setrating::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setrating::execute_slash(ctx, options)),
+        autocomplete: Some(|bot, option| Box::pin(codeforces::rating_autocomplete(bot, option))),
+    },
+    CommandSpec {
+        name: "setchannel",
+        aliases: &[],
+        usage: "#channel",
+        examples: &["#daily"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setchannel::register,
+        prefix: |ctx, args| Box::pin(setchannel::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setchannel::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setdailybonus",
+        aliases: &[],
+        usage: "COINS",
+        examples: &["3", "0"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setdailybonus::register,
+        prefix: |ctx, args| Box::pin(setdailybonus::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setdailybonus::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setsolvedthreshold",
+        aliases: &[],
+        usage: "PERCENT | off",
+        examples: &["50", "off"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setsolvedthreshold::register,
+        prefix: |ctx, args| Box::pin(setsolvedthreshold::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setsolvedthreshold::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setsolvechannel",
+        aliases: &[],
+        usage: "#channel | none",
+        examples: &["#solves", "none"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setsolvechannel::register,
+        prefix: |ctx, args| Box::pin(setsolvechannel::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setsolvechannel::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setsyncreward",
+        aliases: &[],
+        usage: "on | off",
+        examples: &["on", "off"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setsyncreward::register,
+        prefix: |ctx, args| Box::pin(setsyncreward::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setsyncreward::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "account",
+        aliases: &[],
+        usage: "HANDLE",
+        examples: &["tourist"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: account::register,
+        prefix: |ctx, args| Box::pin(account::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(account::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "solved",
+        aliases: &[],
+        usage: "PROBLEM_ID",
+        examples: &["467B", "1850A"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: solved::register,
+        prefix: |ctx, args| Box::pin(solved::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(solved::execute_slash(ctx, options)),
+        autocomplete: Some(|bot, option| Box::pin(solved::autocomplete(bot, option))),
+    },
+    CommandSpec {
+        name: "sync",
+        aliases: &[],
+        usage: "",
+        examples: &[],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: sync::register,
+        prefix: |ctx, _| Box::pin(sync::execute(ctx)),
+        slash: |ctx, _| Box::pin(sync::execute(ctx)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "profile",
+        aliases: &[],
+        usage: "[@user]",
+        examples: &["", "@user"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: profile::register,
+        prefix: |ctx, args| Box::pin(profile::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(profile::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "ratinghistory",
+        aliases: &[],
+        usage: "[@user]",
+        examples: &["", "@user"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: ratinghistory::register,
+        prefix: |ctx, args| Box::pin(ratinghistory::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(ratinghistory::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "balance",
+        aliases: &[],
+        usage: "",
+        examples: &[],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: balance::register,
+        prefix: |ctx, _| Box::pin(balance::execute(ctx)),
+        slash: |ctx, _| Box::pin(balance::execute(ctx)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "leaderboard",
+        aliases: &["top"],
+        usage: "[coins | rating | solved | xp] [PAGE]",
+        examples: &["", "rating", "solved 2"],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: leaderboard::register,
+        prefix: |ctx, args| Box::pin(leaderboard::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(leaderboard::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "config",
+        aliases: &[],
+        usage: "",
+        examples: &[],
+        permissions: Permissions::empty(),
+        delegable: true,
+        guild_only: true,
+        register: config::register,
+        prefix: |ctx, _| Box::pin(config::execute(ctx)),
+        slash: |ctx, _| Box::pin(config::execute(ctx)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setadminrole",
+        aliases: &[],
+        usage: "@role | none",
+        examples: &["@Moderators", "none"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: false,
+        guild_only: true,
+        register: setadminrole::register,
+        prefix: |ctx, args| Box::pin(setadminrole::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setadminrole::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+    CommandSpec {
+        name: "setlanguage",
+        aliases: &["setidioma"],
+        usage: "[en | es]",
+        examples: &["", "es"],
+        permissions: Permissions::MANAGE_GUILD,
+        delegable: true,
+        guild_only: true,
+        register: setlanguage::register,
+        prefix: |ctx, args| Box::pin(setlanguage::execute(ctx, args)),
+        slash: |ctx, options| Box::pin(setlanguage::execute_slash(ctx, options)),
+        autocomplete: None,
+    },
+];
+
+// Look up a command by name or alias
+pub fn find(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|command| command.answers_to(name))
+}
+
+// Split a message into a command spec and its arguments, if it starts with the prefix
+pub fn parse<'m>(content: &'m str, prefix: &str) -> Option<(&'static CommandSpec, &'m str)> {
+    let rest = content.strip_prefix(prefix)?;
+
+    // The command name must follow the prefix directly (`!problem`, not `! problem`)
+    let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+
+    Some((find(name)?, args.trim()))
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn names_and_aliases_are_unique() {
+        let mut seen = HashSet::new();
+
+        for command in COMMANDS {
+            for name in std::iter::once(&command.name).chain(command.aliases) {
+                assert!(
+                    seen.insert(name.to_ascii_lowercase()),
+                    "{name} is used twice"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slash_definitions_use_the_registry_name() {
+        for command in COMMANDS {
+            let definition = serde_json::to_value(command.slash_command()).unwrap();
+            assert_eq!(definition["name"], command.name);
+        }
+    }
+
+    #[test]
+    fn descriptions_exist_in_every_language() {
+        for command in COMMANDS {
+            for language in Language::ALL {
+                let key = format!("command.{}", command.name);
+                let description = i18n::lookup(language, &key)
+                    .unwrap_or_else(|| panic!("{} is missing {}", language.code(), key));
+                // Discord rejects slash command descriptions over 100 characters
+                assert!(description.chars().count() <= 100, "{key} is too long");
+            }
+        }
+    }
+
+    #[test]
+    fn examples_only_hold_arguments() {
+        // Help prepends the prefix and name, so examples must not repeat them
+        for command in COMMANDS {
+            for example in command.examples {
+                let first = example.split_whitespace().next().unwrap_or_default();
+                assert!(!command.answers_to(first), "{example} repeats the name");
+                assert!(!example.starts_with('!'), "{example} repeats the prefix");
+            }
+        }
+    }
+
+    #[test]
+    fn parses_prefix_name_and_arguments() {
+        let (command, args) = parse("!problem 800 1200", "!").unwrap();
+        assert_eq!((command.name, args), ("problem", "800 1200"));
+
+        let (command, args) = parse("?sethora   09:00 ", "?").unwrap();
+        assert_eq!((command.name, args), ("sethour", "09:00"));
+
+        let (command, args) = parse("!BALANCE", "!").unwrap();
+        assert_eq!((command.name, args), ("balance", ""));
+        assert_eq!(command.usage_line("!"), "!balance");
+
+        assert!(parse("!problem 800 1200", "?").is_none());
+        assert!(parse("! problem", "!").is_none());
+        assert!(parse("!unknown", "!").is_none());
+    }
+}
@@ -0,0 +1,361 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{integer_option, string_option, CommandContext, Reply};
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use sqlx::PgPool;
+
+// Members listed on each page
+const PAGE_SIZE: i64 = 10;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /leaderboard slash command
+pub fn register() -> CreateCommand {
+    let ranking = LeaderboardKind::ALL.into_iter().fold(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "ranking",
+            "What to rank members by (defaults to coins)",
+        ),
+        |option, kind| option.add_string_choice(kind.code(), kind.code()),
+    );
+
+    CreateCommand::new("leaderboard")
+        .add_option(ranking)
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "page", "Page to show")
+                .min_int_value(1),
+        )
+}
+
+// =====================
+//   Leaderboard Kinds
+// =====================
+
+// What members are ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum LeaderboardKind {
+    #[default]
+    Coins,
+    Rating,
+    Solved,
+    Xp,
+}
+
+impl LeaderboardKind {
+    const ALL: [LeaderboardKind; 4] = [
+        LeaderboardKind::Coins,
+        LeaderboardKind::Rating,
+        LeaderboardKind::Solved,
+        LeaderboardKind::Xp,
+    ];
+
+    // Name typed by users and used in message keys
+    fn code(self) -> &'static str {
+        match self {
+            LeaderboardKind::Coins => "coins",
+            LeaderboardKind::Rating => "rating",
+            LeaderboardKind::Solved => "solved",
+            LeaderboardKind::Xp => "xp",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.code().eq_ignore_ascii_case(code))
+    }
+
+    // SQL value members are ranked by, for a `user_info u` row
+    fn value_sql(self) -> &'static str {
+        match self {
+            LeaderboardKind::Coins => "COALESCE(u.coins, 0)",
+            LeaderboardKind::Rating => "COALESCE(u.codeforces_rating, 0)::BIGINT",
+            LeaderboardKind::Solved => {
+                "(SELECT COUNT(*) FROM user_solved_problem s
+                WHERE s.guild_id = u.guild_id AND s.user_id = u.user_id)"
+            }
+            LeaderboardKind::Xp => "COALESCE(u.xp, 0)",
+        }
+    }
+
+    // Extra condition on who is ranked (only linked members have a rating)
+    fn member_filter_sql(self) -> &'static str {
+        match self {
+            LeaderboardKind::Rating => "AND u.codeforces_handle IS NOT NULL",
+            _ => "",
+        }
+    }
+
+    // Query ranking the guild's members, with ties sharing a position
+    fn ranked_sql(self) -> String {
+        format!(
+            "SELECT u.user_id, {value} AS value,
+                RANK() OVER (ORDER BY {value} DESC) AS position
+            FROM user_info u
+            WHERE u.guild_id = $1 {filter}",
+            value = self.value_sql(),
+            filter = self.member_filter_sql()
+        )
+    }
+}
+
+// =====================
+//   Argument Parsing
+// =====================
+
+// Parse `[coins|rating|solved|xp] [page]` in any order, returning the first argument that
+// doesn't fit
+fn parse_args(args: &str) -> Result<(LeaderboardKind, i64), String> {
+    let mut kind = None;
+    let mut page = None;
+
+    for word in args.split_whitespace() {
+        match (LeaderboardKind::from_code(word), word.parse::<i64>()) {
+            (Some(parsed), _) if kind.is_none() => kind = Some(parsed),
+            (_, Ok(parsed)) if page.is_none() && parsed >= 1 => page = Some(parsed),
+            _ => return Err(word.to_string()),
+        }
+    }
+
+    Ok((kind.unwrap_or_default(), page.unwrap_or(1)))
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!leaderboard [coins|rating|solved|xp] [page]`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    match parse_args(args) {
+        Ok((kind, page)) => run(ctx, kind, page).await,
+        Err(argument) => Reply::text(ctx.tr_args(
+            "leaderboard.incorrect_argument",
+            &[
+                ("argument", &argument),
+                ("usage", &ctx.usage()),
+                ("prefix", &ctx.settings.prefix),
+            ],
+        )),
+    }
+}
+
+// Handle `/leaderboard [ranking] [page]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let kind = string_option(options, "ranking")
+        .and_then(LeaderboardKind::from_code)
+        .unwrap_or_default();
+    let page = integer_option(options, "page").unwrap_or(1).max(1);
+
+    run(ctx, kind, page).await
+}
+
+// =====================
+//   Leaderboard Queries
+// =====================
+
+// One ranked member
+struct Entry {
+    user_id: i64,
+    value: i64,
+    position: i64,
+}
+
+// Number of ranked members in the guild
+async fn count_members(
+    db: &PgPool,
+    guild_id: i64,
+    kind: LeaderboardKind,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(&format!(
+        "SELECT COUNT(*) FROM ({}) ranked",
+        kind.ranked_sql()
+    ))
+    .bind(guild_id)
+    .fetch_one(db)
+    .await
+}
+
+// Members on a page (1-based), best first
+async fn page_entries(
+    db: &PgPool,
+    guild_id: i64,
+    kind: LeaderboardKind,
+    page: i64,
+) -> Result<Vec<Entry>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (i64, i64, i64)>(&format!(
+        "SELECT user_id, value, position FROM ({}) ranked
+        ORDER BY position, user_id
+        LIMIT $2 OFFSET $3",
+        kind.ranked_sql()
+    ))
+    .bind(guild_id)
+    .bind(PAGE_SIZE)
+    .bind((page - 1) * PAGE_SIZE)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(user_id, value, position)| Entry {
+            user_id,
+            value,
+            position,
+        })
+        .collect())
+}
+
+// The caller's own entry, if they are ranked
+async fn member_entry(
+    db: &PgPool,
+    guild_id: i64,
+    kind: LeaderboardKind,
+    user_id: i64,
+) -> Result<Option<Entry>, sqlx::Error> {
+    let row = sqlx::query_as::<_, (i64, i64, i64)>(&format!(
+        "SELECT user_id, value, position FROM ({}) ranked WHERE user_id = $2",
+        kind.ranked_sql()
+    ))
+    .bind(guild_id)
+    .bind(user_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row.map(|(user_id, value, position)| Entry {
+        user_id,
+        value,
+        position,
+    }))
+}
+
+// =====================
+//   Leaderboard Command Implementation
+// =====================
+
+// Shows a page of the guild's ranking and where the caller stands in it
+async fn run(ctx: &CommandContext<'_>, kind: LeaderboardKind, page: i64) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+    let guild_id = guild_id.get() as i64;
+    let db = &ctx.bot.db;
+
+    let total = match count_members(db, guild_id, kind).await {
+        Ok(total) => total,
+        Err(e) => {
+            eprintln!("Database error counting leaderboard members: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    if total == 0 {
+        return Reply::text(ctx.tr_args("leaderboard.empty", &[("prefix", &ctx.settings.prefix)]));
+    }
+
+    let pages = (total + PAGE_SIZE - 1) / PAGE_SIZE;
+    if page > pages {
+        return Reply::text(ctx.tr_args(
+            "leaderboard.page_out_of_range",
+            &[("page", &page), ("pages", &pages)],
+        ));
+    }
+
+    let (entries, caller) = match tokio::try_join!(
+        page_entries(db, guild_id, kind, page),
+        member_entry(db, guild_id, kind, ctx.author.id.get() as i64),
+    ) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Database error reading leaderboard: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    // =====================
+    //   Discord Embed Creation
+    // =====================
+
+    let unit = ctx.tr(&format!("leaderboard.unit.{}", kind.code()));
+
+    let lines = entries
+        .iter()
+        .map(|entry| {
+            let place = match entry.position {
+                1 => "🥇".to_string(),
+                2 => "🥈".to_string(),
+                3 => "🥉".to_string(),
+                position => format!("`#{}`", position),
+            };
+            format!(
+                "{} <@{}> - **{}** {}",
+                place, entry.user_id, entry.value, unit
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let caller_text = match caller {
+        Some(entry) => ctx.tr_args(
+            "leaderboard.position",
+            &[
+                ("position", &entry.position),
+                ("total", &total),
+                ("value", &entry.value),
+                ("unit", &unit),
+            ],
+        ),
+        None => ctx.tr_args(
+            "leaderboard.not_ranked",
+            &[("prefix", &ctx.settings.prefix)],
+        ),
+    };
+
+    let embed = CreateEmbed::new()
+        .title(ctx.tr_args(
+            "leaderboard.title",
+            &[(
+                "ranking",
+                &ctx.tr(&format!("leaderboard.kind.{}", kind.code())),
+            )],
+        ))
+        .description(lines)
+        .field(ctx.tr("leaderboard.your_position"), caller_text, false)
+        .footer(CreateEmbedFooter::new(ctx.tr_args(
+            "leaderboard.footer",
+            &[
+                ("page", &page),
+                ("pages", &pages),
+                ("prefix", &ctx.settings.prefix),
+                ("ranking", &kind.code()),
+                ("next", &(page % pages + 1)),
+            ],
+        )));
+
+    Reply::embed(embed)
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ranking_and_page_in_any_order() {
+        assert_eq!(parse_args(""), Ok((LeaderboardKind::Coins, 1)));
+        assert_eq!(parse_args("rating"), Ok((LeaderboardKind::Rating, 1)));
+        assert_eq!(parse_args("3 SOLVED"), Ok((LeaderboardKind::Solved, 3)));
+        assert_eq!(parse_args("xp 2"), Ok((LeaderboardKind::Xp, 2)));
+
+        assert_eq!(parse_args("level"), Err("level".to_string()));
+        assert_eq!(parse_args("0"), Err("0".to_string()));
+        assert_eq!(parse_args("coins xp"), Err("xp".to_string()));
+    }
+}
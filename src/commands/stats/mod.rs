@@ -0,0 +1,6 @@
+// =====================
+//   Statistics Commands Module
+// =====================
+
+pub mod leaderboard; // Command to rank the guild's members by coins, rating, solves or XP
+pub mod profile; // Command to summarise a member's account, solves and economy
@@ -0,0 +1,156 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands::context::{mentioned_user, user_option, CommandContext, Reply};
+use chrono::{DateTime, Utc};
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed};
+use serenity::model::application::{CommandOptionType, ResolvedOption};
+use serenity::model::id::UserId;
+use sqlx::Row;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Definition of the /profile slash command
+pub fn register() -> CreateCommand {
+    CreateCommand::new("profile").add_option(CreateCommandOption::new(
+        CommandOptionType::User,
+        "user",
+        "Member to look up (defaults to you)",
+    ))
+}
+
+// =====================
+//   Command Entry Points
+// =====================
+
+// Handle `!profile [@user]`
+pub async fn execute(ctx: &CommandContext<'_>, args: &str) -> Reply {
+    run(ctx, mentioned_user(args).unwrap_or(ctx.author.id)).await
+}
+
+// Handle `/profile [user]`
+pub async fn execute_slash(ctx: &CommandContext<'_>, options: &[ResolvedOption<'_>]) -> Reply {
+    let target = user_option(options, "user").map_or(ctx.author.id, |user| user.id);
+    run(ctx, target).await
+}
+
+// =====================
+//   Profile Command Implementation
+// =====================
+
+// Summarises a member's linked account, solves and economy in one embed
+async fn run(ctx: &CommandContext<'_>, target: UserId) -> Reply {
+    let Some(guild_id) = ctx.guild_id else {
+        return Reply::text(ctx.tr("common.guild_only"));
+    };
+
+    // =====================
+    //   Query Member Profile
+    // =====================
+
+    let row = match sqlx::query(
+        "SELECT u.codeforces_handle, u.codeforces_rating, u.codeforces_rank,
+            u.codeforces_max_rating, u.coins, u.xp, u.level, u.linked_at,
+            (SELECT COUNT(*) FROM user_solved_problem s
+            WHERE s.guild_id = u.guild_id AND s.user_id = u.user_id) AS solved
+        FROM user_info u
+        WHERE u.guild_id = $1 AND u.user_id = $2",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(target.get() as i64)
+    .fetch_optional(&ctx.bot.db)
+    .await
+    {
+        Ok(Some(row)) => row,
+        Ok(None) => {
+            let response = if target == ctx.author.id {
+                ctx.tr_args(
+                    "common.no_linked_account",
+                    &[("prefix", &ctx.settings.prefix)],
+                )
+            } else {
+                ctx.tr_args("common.member_not_linked", &[("user", &target)])
+            };
+            return Reply::text(response);
+        }
+        Err(e) => {
+            eprintln!("Database error in profile command: {}", e);
+            return Reply::text(ctx.tr("common.database_error"));
+        }
+    };
+
+    // Missing values fall back to the column defaults
+    let handle = row
+        .try_get::<Option<String>, _>("codeforces_handle")
+        .unwrap_or_default();
+    let rating = row
+        .try_get::<Option<i32>, _>("codeforces_rating")
+        .unwrap_or_default()
+        .unwrap_or(0);
+    let rank = row
+        .try_get::<Option<String>, _>("codeforces_rank")
+        .unwrap_or_default()
+        .unwrap_or_else(|| "unrated".to_string());
+    let max_rating = row
+        .try_get::<Option<i32>, _>("codeforces_max_rating")
+        .unwrap_or_default()
+        .unwrap_or(0);
+    let coins = row
+        .try_get::<Option<i64>, _>("coins")
+        .unwrap_or_default()
+        .unwrap_or(0);
+    let xp = row
+        .try_get::<Option<i64>, _>("xp")
+        .unwrap_or_default()
+        .unwrap_or(0);
+    let level = row
+        .try_get::<Option<i64>, _>("level")
+        .unwrap_or_default()
+        .unwrap_or(1);
+    let linked_at = row
+        .try_get::<Option<DateTime<Utc>>, _>("linked_at")
+        .unwrap_or_default();
+    let solved = row.try_get::<i64, _>("solved").unwrap_or(0);
+
+    // =====================
+    //   Discord Embed Creation
+    // =====================
+
+    let handle_text = match &handle {
+        Some(handle) => format!("[{}](https://codeforces.com/profile/{})", handle, handle),
+        None => ctx.tr("profile.not_linked").to_string(),
+    };
+
+    // Discord renders the timestamp as a date in each reader's own locale
+    let linked_text = match linked_at {
+        Some(linked_at) => format!("<t:{}:D>", linked_at.timestamp()),
+        None => ctx.tr("profile.unknown_date").to_string(),
+    };
+
+    let embed = CreateEmbed::new()
+        .title(ctx.tr("profile.title"))
+        .description(format!("<@{}>", target))
+        .field(ctx.tr("profile.handle"), handle_text, true)
+        .field(ctx.tr("profile.rank"), format!("`{}`", rank), true)
+        .field(
+            ctx.tr("profile.rating"),
+            ctx.tr_args(
+                "profile.rating_value",
+                &[("rating", &rating), ("max", &max_rating)],
+            ),
+            true,
+        )
+        .field(ctx.tr("profile.solved"), solved.to_string(), true)
+        .field(ctx.tr("profile.coins"), coins.to_string(), true)
+        .field(
+            ctx.tr("profile.level"),
+            ctx.tr_args("profile.level_value", &[("level", &level), ("xp", &xp)]),
+            true,
+        )
+        .field(ctx.tr("profile.linked_at"), linked_text, false);
+
+    Reply::embed(embed)
+}
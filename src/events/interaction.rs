@@ -0,0 +1,111 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::commands;
+use crate::commands::context::{CommandContext, Reply};
+use crate::commands::{permissions, registry};
+use crate::i18n;
+use crate::Bot;
+use serenity::builder::{CreateAutocompleteResponse, CreateInteractionResponse};
+use serenity::cache::Cache;
+use serenity::http::Http;
+use serenity::model::application::{Command, CommandInteraction, Interaction};
+use std::sync::Arc;
+
+// =====================
+//   Slash Command Registration
+// =====================
+
+// Register (or update) every slash command globally.
+// Discord may take a while to show changes to global commands in clients.
+pub async fn register_commands(http: &Arc<Http>) {
+    match Command::set_global_commands(http, commands::slash_commands()).await {
+        Ok(registered) => println!("Registered {} slash commands ✅", registered.len()),
+        Err(e) => eprintln!("Error registering slash commands: {}", e),
+    }
+}
+
+// =====================
+//   Interaction Event Handler
+// =====================
+
+// Main interaction handler function - dispatches slash commands and autocomplete requests
+pub async fn handle_interaction(
+    bot: &Bot,
+    http: &Arc<Http>,
+    cache: &Arc<Cache>,
+    interaction: Interaction,
+) {
+    match interaction {
+        Interaction::Command(command) => handle_command(bot, http, cache, &command).await,
+        Interaction::Autocomplete(autocomplete) => {
+            handle_autocomplete(bot, http, &autocomplete).await
+        }
+        _ => {}
+    }
+}
+
+// =====================
+//   Slash Command Dispatching
+// =====================
+
+// Run a slash command and answer with its reply
+async fn handle_command(
+    bot: &Bot,
+    http: &Arc<Http>,
+    cache: &Arc<Cache>,
+    command: &CommandInteraction,
+) {
+    // Acknowledge right away - Discord only waits 3 seconds for the first response,
+    // and commands that hit Codeforces can take longer than that
+    if let Err(e) = command.defer(http).await {
+        eprintln!("Error deferring /{} interaction: {}", command.data.name, e);
+        return;
+    }
+
+    let settings = bot.settings.get(command.guild_id).await;
+
+    let reply = match registry::find(&command.data.name) {
+        Some(spec) => {
+            let ctx = CommandContext::from_interaction(bot, http, cache, command, spec, settings);
+
+            // Refuse server-only commands in DMs and members without the required permissions
+            match permissions::check(&ctx).await {
+                Ok(()) => (spec.slash)(&ctx, &command.data.options()).await,
+                Err(refusal) => Reply::text(refusal),
+            }
+        }
+        // Commands removed from the bot can linger in clients for a while
+        None => Reply::text(i18n::tr(settings.language, "common.unknown_command")),
+    };
+
+    if let Err(e) = command
+        .edit_response(http, reply.into_interaction_response())
+        .await
+    {
+        eprintln!("Error sending /{} response: {}", command.data.name, e);
+    }
+}
+
+// =====================
+//   Autocomplete Dispatching
+// =====================
+
+// Suggest values for the option the user is currently typing
+async fn handle_autocomplete(bot: &Bot, http: &Arc<Http>, autocomplete: &CommandInteraction) {
+    let Some(option) = autocomplete.data.autocomplete() else {
+        return;
+    };
+
+    let handler = registry::find(&autocomplete.data.name).and_then(|spec| spec.autocomplete);
+    let response = match handler {
+        Some(handler) => handler(bot, &option).await,
+        None => CreateAutocompleteResponse::new(),
+    };
+
+    // Failures here only mean the user sees no suggestions
+    let _ = autocomplete
+        .create_response(http, CreateInteractionResponse::Autocomplete(response))
+        .await;
+}
@@ -2,14 +2,10 @@
 //   Imports and Dependencies
 // =====================
 
-use crate::commands;
-use crate::commands::codeforces::account;
-use crate::commands::codeforces::problem;
-use crate::commands::codeforces::setchannel;
-use crate::commands::codeforces::sethour;
-use crate::commands::codeforces::solved;
-use crate::commands::economy::balance;
+use crate::commands::context::{CommandContext, Reply};
+use crate::commands::{permissions, registry};
 use crate::Bot;
+use serenity::cache::Cache;
 use serenity::http::Http;
 use serenity::model::channel::Message;
 use std::sync::Arc;
@@ -19,7 +15,7 @@ use std::sync::Arc;
 // =====================
 
 // Main message handler function - processes all incoming messages and dispatches commands
-pub async fn handle_message(bot: &Bot, http: &Arc<Http>, msg: Message) {
+pub async fn handle_message(bot: &Bot, http: &Arc<Http>, cache: &Arc<Cache>, msg: Message) {
     // =====================
     //   Bot Message Filter
     // =====================
@@ -33,50 +29,28 @@ pub async fn handle_message(bot: &Bot, http: &Arc<Http>, msg: Message) {
     //   Command Dispatching
     // =====================
 
-    // Handle ping command - simple connectivity test
-    if msg.content == "!ping" {
-        let _ = commands::ping::execute(http, &msg).await;
-    }
-
-    // Handle problem command - get random Codeforces problem with specified difficulty range
-    if msg.content.starts_with("!problem ") {
-        let _ = problem::execute(http, &msg, &bot.db).await;
-    }
-
-    // Handle set hour command - configure daily problem posting time
-    if msg.content.starts_with("!sethora ") {
-        let _ = sethour::execute(http, &msg, &bot.db).await;
-    }
-
-    // Handle set channel command - configure which channel receives daily problems
-    if msg.content.starts_with("!setchannel ") {
-        let _ = setchannel::execute(http, &msg, &bot.db).await;
-    }
-
-    // Handle account command - link Codeforces account to Discord user
-    if msg.content.starts_with("!account ") {
-        if let Some(guild_id) = msg.guild_id {
-            if let Ok(guild) = http.get_guild(guild_id).await {
-                let _ = account::execute(http, &msg, &bot.db, &guild).await;
-            }
-        }
-    }
+    // Each guild can configure its own prefix (`!` by default)
+    let settings = bot.settings.get(msg.guild_id).await;
 
-    // Handle solved command - verify if a problem is solved and mark it
-    if msg.content.starts_with("!solved ") {
-        if let Some(guild_id) = msg.guild_id {
-            if let Ok(guild) = http.get_guild(guild_id).await {
-                let _ = solved::execute(http, &msg, &bot.db, &guild).await;
-            }
-        }
-    }
-
-    // Handle balance command - show user's coin balance
-    if msg.content == "!balance" {
-        if let Some(guild_id) = msg.guild_id {
-            if let Ok(guild) = http.get_guild(guild_id).await {
-                let _ = balance::execute(http, &msg, &bot.db, &guild).await;
-            }
-        }
+    // Find the command named right after the prefix; anything else is ordinary chat
+    let Some((command, args)) = registry::parse(&msg.content, &settings.prefix) else {
+        return;
+    };
+
+    let ctx = CommandContext::from_message(bot, http, cache, &msg, command, settings);
+
+    // Refuse server-only commands in DMs and members without the required permissions
+    let reply = match permissions::check(&ctx).await {
+        Ok(()) => (command.prefix)(&ctx, args).await,
+        Err(refusal) => Reply::text(refusal),
+    };
+
+    // Send the command's reply to the channel the command was used in
+    if let Err(e) = msg
+        .channel_id
+        .send_message(http, reply.into_message())
+        .await
+    {
+        eprintln!("Error sending {} reply: {}", command.name, e);
     }
 }
@@ -4,4 +4,5 @@
 
 // Module declarations for event handling functionality
 pub mod guild; // Guild-related events (join, leave, etc.)
+pub mod interaction; // Interaction events (slash commands, autocomplete)
 pub mod message; // Message-related events (commands, reactions, etc.)
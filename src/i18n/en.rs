@@ -0,0 +1,600 @@
+// =====================
+//   English Messages
+// =====================
+
+// Placeholders are written as `{name}` and filled in by `tr_args`
+pub static MESSAGES: &[(&str, &str)] = &[
+    // =====================
+    //   Shared Messages
+    // =====================
+    (
+        "common.guild_only",
+        "❌ This command can only be used in a server",
+    ),
+    (
+        "common.database_error",
+        "❌ Error accessing the database. Try again later.",
+    ),
+    ("common.unknown_command", "❌ Unknown command"),
+    (
+        "common.no_linked_account",
+        "❌ You don't have a linked Codeforces account. Use `{prefix}account your_handle` first.",
+    ),
+    (
+        "common.handle_gone",
+        "❌ The handle `{handle}` no longer exists on Codeforces.",
+    ),
+    (
+        "common.member_not_linked",
+        "❌ <@{user}> doesn't have a linked Codeforces account.",
+    ),
+    ("common.no_tags", "No tags"),
+    // =====================
+    //   Permissions
+    // =====================
+    (
+        "permissions.check_failed",
+        "❌ Could not verify your permissions, try again later",
+    ),
+    (
+        "permissions.denied",
+        "🔒 You need the **{permissions}** permission to use `{command}`",
+    ),
+    (
+        "permissions.denied_or_role",
+        "🔒 You need the **{permissions}** permission or the <@&{role}> role to use `{command}`",
+    ),
+    // =====================
+    //   Codeforces API Errors
+    // =====================
+    (
+        "api.not_found",
+        "❌ Codeforces couldn't find what was requested: `{comment}`",
+    ),
+    (
+        "api.rate_limited",
+        "⏳ Codeforces is rate limiting us right now, try again in a few minutes.",
+    ),
+    (
+        "api.failed",
+        "❌ Codeforces rejected the request: `{comment}`",
+    ),
+    (
+        "api.timeout",
+        "⌛ Codeforces took too long to respond, try again later.",
+    ),
+    (
+        "api.network",
+        "❌ Error connecting to the Codeforces API. Try again later.",
+    ),
+    // =====================
+    //   Command Descriptions
+    // =====================
+    (
+        "command.help",
+        "List the commands you can use, or show how to use one",
+    ),
+    ("command.ping", "Check that the bot is online"),
+    ("command.daily", "Show the server's current daily problem again"),
+    (
+        "command.problem",
+        "Get a random Codeforces problem, optionally filtered by rating and tags",
+    ),
+    ("command.sethour", "Set the time of the daily problem"),
+    (
+        "command.setrating",
+        "View or set the rating range of the daily problem",
+    ),
+    (
+        "command.setdailybonus",
+        "Set the extra coins for solving the daily problem",
+    ),
+    (
+        "command.setsolvedthreshold",
+        "Skip daily problems already solved by most linked members",
+    ),
+    (
+        "command.setsolvechannel",
+        "Set or disable the channel that announces detected solves",
+    ),
+    (
+        "command.setsyncreward",
+        "Choose whether solves imported with sync award coins",
+    ),
+    (
+        "command.setchannel",
+        "Set the channel that receives the daily problem",
+    ),
+    ("command.account", "Link your Codeforces account"),
+    (
+        "command.solved",
+        "Verify a solved Codeforces problem and earn a coin",
+    ),
+    (
+        "command.sync",
+        "Import every problem your linked Codeforces handle has solved",
+    ),
+    (
+        "command.ratinghistory",
+        "Show the Codeforces rating history of a linked member",
+    ),
+    ("command.balance", "Show your coin balance"),
+    (
+        "command.profile",
+        "Show a member's Codeforces account, solves, coins and level",
+    ),
+    (
+        "command.leaderboard",
+        "Rank the server's members by coins, rating, solved problems or XP",
+    ),
+    ("command.config", "Show the server's bot configuration"),
+    (
+        "command.setadminrole",
+        "Choose the role allowed to configure the bot",
+    ),
+    (
+        "command.setlanguage",
+        "View or change the language of the bot's replies",
+    ),
+    // =====================
+    //   Help
+    // =====================
+    ("help.list_title", "📖 Available commands"),
+    (
+        "help.list_footer",
+        "Use {prefix}help <command> for details. Every command also works as a slash command.",
+    ),
+    (
+        "help.unknown",
+        "❌ Unknown command `{command}`. Use `{prefix}help` to list the available commands",
+    ),
+    ("help.usage", "⌨️ Usage"),
+    ("help.aliases", "🔁 Aliases"),
+    ("help.examples", "💡 Examples"),
+    ("help.permissions", "🛡️ Permissions"),
+    ("help.everyone", "Everyone"),
+    (
+        "help.permission_or_role",
+        "{permissions} or the <@&{role}> role",
+    ),
+    ("help.servers_only", "Servers only"),
+    ("help.not_allowed", "🔒 You can't run this command here"),
+    // =====================
+    //   Ping
+    // =====================
+    ("ping.pong", "🏓 Pong!"),
+    // =====================
+    //   Balance
+    // =====================
+    (
+        "balance.summary",
+        "💰 **Coin balance**\n\n\
+        👤 User: <@{user}>\n\
+        🪙 **Coins:** `{coins}`\n\n\
+        💡 *Solve Codeforces problems with `{prefix}solved` to earn more coins!*",
+    ),
+    (
+        "balance.no_profile",
+        "❌ You don't have a profile in this server. Use `{prefix}account your_handle` to create one and start earning coins.",
+    ),
+    // =====================
+    //   Leaderboard
+    // =====================
+    (
+        "leaderboard.incorrect_argument",
+        "❌ Incorrect argument `{argument}`. Use: {usage} (example: {prefix}leaderboard rating 2)",
+    ),
+    (
+        "leaderboard.empty",
+        "ℹ️ Nobody is on this leaderboard yet. Use `{prefix}account your_handle` to join",
+    ),
+    (
+        "leaderboard.page_out_of_range",
+        "❌ Page {page} doesn't exist, the leaderboard has {pages} page(s)",
+    ),
+    ("leaderboard.title", "🏆 Leaderboard - {ranking}"),
+    ("leaderboard.your_position", "📍 Your position"),
+    (
+        "leaderboard.position",
+        "#{position} of {total} with **{value}** {unit}",
+    ),
+    (
+        "leaderboard.not_ranked",
+        "You're not on this leaderboard yet. Use `{prefix}account your_handle` to join",
+    ),
+    (
+        "leaderboard.footer",
+        "Page {page}/{pages} · Next: {prefix}leaderboard {ranking} {next}",
+    ),
+    ("leaderboard.kind.coins", "Coins"),
+    ("leaderboard.kind.rating", "Codeforces rating"),
+    ("leaderboard.kind.solved", "Solved problems"),
+    ("leaderboard.kind.xp", "XP"),
+    ("leaderboard.unit.coins", "coins"),
+    ("leaderboard.unit.rating", "rating"),
+    ("leaderboard.unit.solved", "solved"),
+    ("leaderboard.unit.xp", "XP"),
+    // =====================
+    //   Account
+    // =====================
+    (
+        "account.missing_handle",
+        "❌ Please provide a Codeforces handle. Use: `{usage}`",
+    ),
+    (
+        "account.linked",
+        "✅ **Codeforces account linked successfully!**\n\n\
+        👤 **Handle:** `{handle}`\n\
+        🏆 **Rating:** `{rating}`\n\
+        🎯 **Rank:** `{rank}`\n\
+        📊 **Max rating:** `{max_rating}`",
+    ),
+    (
+        "account.save_error",
+        "❌ Error saving the information to the database. Try again later.",
+    ),
+    (
+        "account.not_found",
+        "❌ User `{handle}` wasn't found on Codeforces. Check that the handle is correct.",
+    ),
+    (
+        "account.verification_started",
+        "🔐 **Verify that `{handle}` is your account**\n\n\
+        1. Submit any code that gives a **Compilation error** to [{problem}]({url})\n\
+        2. Run `{command}` again\n\n\
+        You have {minutes} minutes, the verification expires <t:{expires}:R>.",
+    ),
+    (
+        "account.verification_pending",
+        "⏳ No compilation error to [{problem}]({url}) from `{handle}` was found yet. Submit one and run `{command}` again before <t:{expires}:t>.",
+    ),
+    (
+        "account.verification_unavailable",
+        "❌ Couldn't pick a problem for the verification. Try again later.",
+    ),
+    // =====================
+    //   Solved
+    // =====================
+    (
+        "solved.missing_problem",
+        "❌ Please provide a problem ID. Use: `{usage}` (e.g. `{prefix}solved 467B`)",
+    ),
+    (
+        "solved.invalid_format",
+        "❌ Invalid problem format. Use `467B` (contest_id + index) or the problem link",
+    ),
+    (
+        "solved.already_solved",
+        "ℹ️ Problem `{problem}` is already marked as solved.",
+    ),
+    (
+        "solved.check_error",
+        "❌ Error checking the problem status. Try again later.",
+    ),
+    (
+        "solved.handle_gone",
+        "❌ The handle `{handle}` no longer exists on Codeforces. Link your account again with `{prefix}account your_handle`.",
+    ),
+    (
+        "solved.not_solved",
+        "❌ **Problem not solved**\n\n\
+        🔍 No accepted solution was found for problem `{problem}`\n\
+        👤 User: `{handle}`\n\n\
+        💡 **Possible reasons:**\n\
+        • The problem hasn't been solved yet\n\
+        • The solution didn't pass every test case\n\
+        • The problem doesn't exist or the format is wrong\n\n\
+        Keep trying! 💪",
+    ),
+    (
+        "solved.save_error",
+        "❌ Error marking the problem as solved. Try again later.",
+    ),
+    (
+        "solved.verified",
+        "🎉 **Solved problem verified!**\n\n\
+        ✅ Problem `{problem}` has been marked as solved.\n\
+        👤 User: `{handle}`\n\
+        💰 **+1 coin earned!**\n\
+        🏆 Congratulations on the accepted solution!",
+    ),
+    (
+        "solved.daily_bonus",
+        "📅 **+{coins} coins** bonus for solving the daily problem!",
+    ),
+    // =====================
+    //   Sync
+    // =====================
+    (
+        "sync.imported",
+        "📥 Imported **{imported}** new solved problems from `{handle}` ({accepted} accepted in total)",
+    ),
+    (
+        "sync.imported_coins",
+        "📥 Imported **{imported}** new solved problems from `{handle}` ({accepted} accepted in total)\n🪙 +{coins} coins",
+    ),
+    ("sync.save_error", "❌ Error importing your solved problems. Try again later."),
+    // =====================
+    //   Solves
+    // =====================
+    (
+        "solves.announcement",
+        "🎉 <@{user}> solved [{problem} - {name}]({url}) and earned {coins} coin(s)",
+    ),
+    // =====================
+    //   Profile
+    // =====================
+    ("profile.title", "👤 Profile"),
+    ("profile.handle", "🔗 Codeforces"),
+    ("profile.not_linked", "Not linked"),
+    ("profile.rank", "🎯 Rank"),
+    ("profile.rating", "🏆 Rating"),
+    ("profile.rating_value", "**{rating}** (max {max})"),
+    ("profile.solved", "✅ Verified solves"),
+    ("profile.coins", "🪙 Coins"),
+    ("profile.level", "⭐ Level"),
+    ("profile.level_value", "**{level}** ({xp} XP)"),
+    ("profile.linked_at", "📅 Linked since"),
+    ("profile.unknown_date", "Unknown"),
+    // =====================
+    //   Rating History
+    // =====================
+    (
+        "ratinghistory.no_contests",
+        "ℹ️ `{handle}` hasn't taken part in any rated contest yet.",
+    ),
+    ("ratinghistory.title", "📈 Rating history of {handle}"),
+    ("ratinghistory.contests", "🏁 Contests"),
+    ("ratinghistory.current", "🏆 Current rating"),
+    ("ratinghistory.current_value", "**{current}** (max {max})"),
+    ("ratinghistory.deltas", "📊 Best / worst change"),
+    ("ratinghistory.recent", "🕒 Latest contests"),
+    // =====================
+    //   Problem
+    // =====================
+    (
+        "problem.incorrect_argument",
+        "❌ Incorrect argument `{argument}`. Use: {usage} (example: {prefix}problem 1200 1600 dp -math unsolved)",
+    ),
+    (
+        "problem.min_above_max",
+        "❌ `min_rating` can't be higher than `max_rating`",
+    ),
+    (
+        "problem.range_error",
+        "❌ Error reading this server's rating range",
+    ),
+    (
+        "problem.no_results",
+        "❌ No problems found matching those filters",
+    ),
+    (
+        "problem.unsolved_needs_account",
+        "❌ `unsolved` needs a linked Codeforces account. Use `{prefix}account your_handle` first",
+    ),
+    ("problem.account_error", "❌ Error reading your linked account"),
+    ("problem.title", "💻 Codeforces Problem"),
+    ("problem.greeting", "Hello <@{user}>! Here's your problem:"),
+    ("problem.difficulty", "🎯 Difficulty"),
+    ("problem.tags", "🏷️ Tags"),
+    ("problem.link", "🔗 Link"),
+    ("problem.solve_link", "[Solve problem]({url})"),
+    // =====================
+    //   Daily Problem
+    // =====================
+    ("daily.title", "💻 Daily Codeforces Problem"),
+    ("daily.greeting", "Hello <@&{role}>! Here's your daily problem:"),
+    (
+        "daily.none",
+        "📭 No daily problem has been posted here yet. An admin can set it up with `{prefix}setchannel #channel`",
+    ),
+    (
+        "daily.current",
+        "📅 Current daily problem, posted <t:{posted}:R> ([original post]({url}))",
+    ),
+    (
+        "daily.no_problems",
+        "❌ No problems found in that difficulty range.",
+    ),
+    // =====================
+    //   Set Hour
+    // =====================
+    (
+        "sethour.incorrect_format",
+        "❌ Incorrect format. Use: {usage} (example: {prefix}sethour 09:00)",
+    ),
+    (
+        "sethour.invalid_time",
+        "❌ Invalid time. Use HH:MM format (00:00 - 23:59)",
+    ),
+    ("sethour.saved", "⏰ Daily report time set to {time}"),
+    ("sethour.save_error", "❌ Error saving the time"),
+    // =====================
+    //   Set Channel
+    // =====================
+    (
+        "setchannel.incorrect_format",
+        "❌ Incorrect format. Use: {usage}",
+    ),
+    ("setchannel.invalid_channel", "❌ Invalid channel ID"),
+    (
+        "setchannel.saved",
+        "✅ Daily report channel set to <#{channel}>",
+    ),
+    ("setchannel.save_error", "❌ Error saving the channel"),
+    // =====================
+    //   Set Solved Threshold
+    // =====================
+    (
+        "setsolvedthreshold.incorrect_format",
+        "❌ Incorrect format. Use: {usage}",
+    ),
+    (
+        "setsolvedthreshold.out_of_range",
+        "❌ The threshold must be a percentage between 0 and 100",
+    ),
+    (
+        "setsolvedthreshold.saved",
+        "✅ Daily problems solved by more than **{percent}%** of linked members will be skipped",
+    ),
+    (
+        "setsolvedthreshold.disabled",
+        "✅ Daily problems are no longer skipped for being already solved",
+    ),
+    (
+        "setsolvedthreshold.save_error",
+        "❌ Error saving the solved threshold",
+    ),
+    // =====================
+    //   Set Solve Channel
+    // =====================
+    ("setsolvechannel.incorrect_format", "❌ Incorrect format. Use: {usage}"),
+    (
+        "setsolvechannel.saved",
+        "✅ Detected solves will be announced in <#{channel}>",
+    ),
+    ("setsolvechannel.disabled", "✅ Solve announcements disabled"),
+    ("setsolvechannel.save_error", "❌ Error saving the solve channel"),
+    // =====================
+    //   Set Daily Bonus
+    // =====================
+    (
+        "setdailybonus.incorrect_format",
+        "❌ Incorrect format. Use: {usage}",
+    ),
+    (
+        "setdailybonus.out_of_range",
+        "❌ The bonus must be between 0 and {max} coins",
+    ),
+    (
+        "setdailybonus.saved",
+        "✅ Solving the daily problem now awards **{coins}** extra coins",
+    ),
+    (
+        "setdailybonus.disabled",
+        "✅ Daily problem bonus disabled",
+    ),
+    (
+        "setdailybonus.save_error",
+        "❌ Error saving the daily bonus",
+    ),
+    // =====================
+    //   Set Sync Reward
+    // =====================
+    (
+        "setsyncreward.incorrect_format",
+        "❌ Incorrect format. Use: {usage}",
+    ),
+    (
+        "setsyncreward.enabled",
+        "✅ Problems imported with sync will award coins",
+    ),
+    (
+        "setsyncreward.disabled",
+        "✅ Problems imported with sync will no longer award coins",
+    ),
+    (
+        "setsyncreward.save_error",
+        "❌ Error saving the sync reward setting",
+    ),
+    // =====================
+    //   Set Rating
+    // =====================
+    (
+        "setrating.missing_bound",
+        "❌ Provide both `min_rating` and `max_rating`",
+    ),
+    (
+        "setrating.incorrect_format",
+        "❌ Incorrect format. Use: {usage} (example: {prefix}setrating 800 1400)",
+    ),
+    (
+        "setrating.out_of_range",
+        "❌ `{rating}` is out of range. Ratings go from {min} to {max}",
+    ),
+    (
+        "setrating.not_multiple",
+        "❌ `{rating}` is not a valid problem rating. Ratings are multiples of 100",
+    ),
+    (
+        "setrating.min_above_max",
+        "❌ The minimum rating ({min}) can't be higher than the maximum ({max})",
+    ),
+    (
+        "setrating.current",
+        "🎯 Daily problems are rated **{min} - {max}**. Change it with `{usage}`",
+    ),
+    ("setrating.read_error", "❌ Error reading the rating range"),
+    (
+        "setrating.saved",
+        "✅ Daily problems will be rated between **{min}** and **{max}**",
+    ),
+    ("setrating.save_error", "❌ Error saving the rating range"),
+    // =====================
+    //   Set Admin Role
+    // =====================
+    (
+        "setadminrole.incorrect_format",
+        "❌ Incorrect format. Use: {usage}",
+    ),
+    ("setadminrole.invalid_role", "❌ Invalid role ID"),
+    (
+        "setadminrole.saved",
+        "✅ Members with <@&{role}> can now configure the bot",
+    ),
+    (
+        "setadminrole.removed",
+        "✅ Bot admin role removed, only members with Manage Server can configure the bot",
+    ),
+    ("setadminrole.save_error", "❌ Error saving the admin role"),
+    // =====================
+    //   Set Language
+    // =====================
+    (
+        "setlanguage.current",
+        "🌐 The bot replies in **{language}** here. Change it with `{usage}` ({languages})",
+    ),
+    (
+        "setlanguage.unknown",
+        "❌ Unknown language `{language}`. Available languages: {languages}",
+    ),
+    ("setlanguage.saved", "✅ The bot will now reply in **{language}**"),
+    ("setlanguage.save_error", "❌ Error saving the language"),
+    // =====================
+    //   Config
+    // =====================
+    (
+        "config.not_configured",
+        "❌ This server has no configuration yet",
+    ),
+    (
+        "config.read_error",
+        "❌ Error reading the server configuration",
+    ),
+    ("config.title", "⚙️ Server configuration"),
+    ("config.prefix", "🔤 Prefix"),
+    ("config.language", "🌐 Language"),
+    ("config.admin_role", "🛡️ Admin role"),
+    ("config.admin_unset", "Not set (Manage Server only)"),
+    ("config.channel", "📢 Daily channel"),
+    (
+        "config.channel_unset",
+        "Not set (use `{prefix}setchannel #channel`)",
+    ),
+    ("config.solve_channel", "🎉 Solve announcements"),
+    (
+        "config.solve_channel_unset",
+        "Disabled (use `{prefix}setsolvechannel #channel`)",
+    ),
+    ("config.sync_reward", "🪙 Coins for synced solves"),
+    ("config.sync_reward_on", "Enabled"),
+    ("config.sync_reward_off", "Disabled"),
+    ("config.daily_bonus", "📅 Daily solve bonus"),
+    ("config.daily_bonus_value", "{coins} coins"),
+    ("config.solved_threshold", "🚫 Skip widely solved dailies"),
+    ("config.solved_threshold_value", "Solved by more than {percent}%"),
+    ("config.solved_threshold_off", "Disabled"),
+    ("config.time", "⏰ Daily time"),
+    ("config.time_value", "{time} (bot time)"),
+    ("config.rating", "🎯 Daily rating range"),
+];
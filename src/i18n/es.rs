@@ -0,0 +1,621 @@
+// =====================
+//   Spanish Messages
+// =====================
+
+// Same keys and placeholders as the English catalog
+pub static MESSAGES: &[(&str, &str)] = &[
+    // =====================
+    //   Shared Messages
+    // =====================
+    (
+        "common.guild_only",
+        "❌ Este comando solo se puede usar en un servidor",
+    ),
+    (
+        "common.database_error",
+        "❌ Error al acceder a la base de datos. Intenta de nuevo más tarde.",
+    ),
+    ("common.unknown_command", "❌ Comando desconocido"),
+    (
+        "common.no_linked_account",
+        "❌ No tienes una cuenta de Codeforces vinculada. Usa `{prefix}account tu_handle` primero.",
+    ),
+    (
+        "common.handle_gone",
+        "❌ El handle `{handle}` ya no existe en Codeforces.",
+    ),
+    (
+        "common.member_not_linked",
+        "❌ <@{user}> no tiene una cuenta de Codeforces vinculada.",
+    ),
+    ("common.no_tags", "Sin etiquetas"),
+    // =====================
+    //   Permissions
+    // =====================
+    (
+        "permissions.check_failed",
+        "❌ No se pudieron verificar tus permisos, intenta de nuevo más tarde",
+    ),
+    (
+        "permissions.denied",
+        "🔒 Necesitas el permiso **{permissions}** para usar `{command}`",
+    ),
+    (
+        "permissions.denied_or_role",
+        "🔒 Necesitas el permiso **{permissions}** o el rol <@&{role}> para usar `{command}`",
+    ),
+    // =====================
+    //   Codeforces API Errors
+    // =====================
+    (
+        "api.not_found",
+        "❌ Codeforces no encontró lo solicitado: `{comment}`",
+    ),
+    (
+        "api.rate_limited",
+        "⏳ Codeforces está limitando las solicitudes en este momento. Intenta de nuevo en unos minutos.",
+    ),
+    (
+        "api.failed",
+        "❌ Codeforces rechazó la solicitud: `{comment}`",
+    ),
+    (
+        "api.timeout",
+        "⌛ Codeforces tardó demasiado en responder. Intenta de nuevo más tarde.",
+    ),
+    (
+        "api.network",
+        "❌ Error al conectar con la API de Codeforces. Intenta de nuevo más tarde.",
+    ),
+    // =====================
+    //   Command Descriptions
+    // =====================
+    (
+        "command.help",
+        "Lista los comandos que puedes usar o explica cómo usar uno",
+    ),
+    ("command.ping", "Comprueba que el bot está en línea"),
+    ("command.daily", "Muestra otra vez el problema diario actual del servidor"),
+    (
+        "command.problem",
+        "Obtén un problema aleatorio de Codeforces, filtrado por rating y etiquetas",
+    ),
+    ("command.sethour", "Configura la hora del problema diario"),
+    (
+        "command.setrating",
+        "Consulta o configura el rango de rating del problema diario",
+    ),
+    (
+        "command.setdailybonus",
+        "Configura las monedas extra por resolver el problema diario",
+    ),
+    (
+        "command.setsolvedthreshold",
+        "Evita problemas diarios ya resueltos por la mayoría de miembros vinculados",
+    ),
+    (
+        "command.setsolvechannel",
+        "Configura o desactiva el canal que anuncia los problemas resueltos",
+    ),
+    (
+        "command.setsyncreward",
+        "Elige si los problemas importados con sync otorgan monedas",
+    ),
+    (
+        "command.setchannel",
+        "Configura el canal que recibe el problema diario",
+    ),
+    ("command.account", "Vincula tu cuenta de Codeforces"),
+    (
+        "command.solved",
+        "Verifica un problema resuelto de Codeforces y gana una moneda",
+    ),
+    (
+        "command.sync",
+        "Importa todos los problemas resueltos por tu handle de Codeforces",
+    ),
+    (
+        "command.ratinghistory",
+        "Muestra el historial de rating de Codeforces de un miembro vinculado",
+    ),
+    ("command.balance", "Muestra tu balance de monedas"),
+    (
+        "command.profile",
+        "Muestra la cuenta de Codeforces, problemas, monedas y nivel de un miembro",
+    ),
+    (
+        "command.leaderboard",
+        "Clasifica a los miembros por monedas, rating, problemas resueltos o XP",
+    ),
+    ("command.config", "Muestra la configuración del bot en el servidor"),
+    (
+        "command.setadminrole",
+        "Elige el rol que puede configurar el bot",
+    ),
+    (
+        "command.setlanguage",
+        "Consulta o cambia el idioma de las respuestas del bot",
+    ),
+    // =====================
+    //   Help
+    // =====================
+    ("help.list_title", "📖 Comandos disponibles"),
+    (
+        "help.list_footer",
+        "Usa {prefix}help <comando> para ver detalles. Todos los comandos también funcionan como comandos de barra.",
+    ),
+    (
+        "help.unknown",
+        "❌ Comando desconocido `{command}`. Usa `{prefix}help` para ver los comandos disponibles",
+    ),
+    ("help.usage", "⌨️ Uso"),
+    ("help.aliases", "🔁 Alias"),
+    ("help.examples", "💡 Ejemplos"),
+    ("help.permissions", "🛡️ Permisos"),
+    ("help.everyone", "Todos"),
+    ("help.permission_or_role", "{permissions} o el rol <@&{role}>"),
+    ("help.servers_only", "Solo en servidores"),
+    (
+        "help.not_allowed",
+        "🔒 No puedes usar este comando aquí",
+    ),
+    // =====================
+    //   Ping
+    // =====================
+    ("ping.pong", "🏓 Pong!"),
+    // =====================
+    //   Balance
+    // =====================
+    (
+        "balance.summary",
+        "💰 **Balance de monedas**\n\n\
+        👤 Usuario: <@{user}>\n\
+        🪙 **Monedas:** `{coins}`\n\n\
+        💡 *Resuelve problemas de Codeforces con `{prefix}solved` para ganar más monedas!*",
+    ),
+    (
+        "balance.no_profile",
+        "❌ No tienes un perfil en este servidor. Usa `{prefix}account tu_handle` para crear uno y comenzar a ganar monedas.",
+    ),
+    // =====================
+    //   Leaderboard
+    // =====================
+    (
+        "leaderboard.incorrect_argument",
+        "❌ Argumento incorrecto `{argument}`. Uso: {usage} (ejemplo: {prefix}leaderboard rating 2)",
+    ),
+    (
+        "leaderboard.empty",
+        "ℹ️ Todavía no hay nadie en esta clasificación. Usa `{prefix}account tu_handle` para unirte",
+    ),
+    (
+        "leaderboard.page_out_of_range",
+        "❌ La página {page} no existe, la clasificación tiene {pages} página(s)",
+    ),
+    ("leaderboard.title", "🏆 Clasificación - {ranking}"),
+    ("leaderboard.your_position", "📍 Tu posición"),
+    (
+        "leaderboard.position",
+        "#{position} de {total} con **{value}** {unit}",
+    ),
+    (
+        "leaderboard.not_ranked",
+        "Todavía no estás en esta clasificación. Usa `{prefix}account tu_handle` para unirte",
+    ),
+    (
+        "leaderboard.footer",
+        "Página {page}/{pages} · Siguiente: {prefix}leaderboard {ranking} {next}",
+    ),
+    ("leaderboard.kind.coins", "Monedas"),
+    ("leaderboard.kind.rating", "Rating de Codeforces"),
+    ("leaderboard.kind.solved", "Problemas resueltos"),
+    ("leaderboard.kind.xp", "XP"),
+    ("leaderboard.unit.coins", "monedas"),
+    ("leaderboard.unit.rating", "de rating"),
+    ("leaderboard.unit.solved", "resueltos"),
+    ("leaderboard.unit.xp", "XP"),
+    // =====================
+    //   Account
+    // =====================
+    (
+        "account.missing_handle",
+        "❌ Por favor proporciona un handle de Codeforces. Uso: `{usage}`",
+    ),
+    (
+        "account.linked",
+        "✅ **Cuenta de Codeforces vinculada exitosamente!**\n\n\
+        👤 **Handle:** `{handle}`\n\
+        🏆 **Rating:** `{rating}`\n\
+        🎯 **Rank:** `{rank}`\n\
+        📊 **Rating Máximo:** `{max_rating}`",
+    ),
+    (
+        "account.save_error",
+        "❌ Error al guardar la información en la base de datos. Intenta de nuevo más tarde.",
+    ),
+    (
+        "account.not_found",
+        "❌ No se encontró el usuario `{handle}` en Codeforces. Verifica que el handle sea correcto.",
+    ),
+    (
+        "account.verification_started",
+        "🔐 **Verifica que `{handle}` es tu cuenta**\n\n\
+        1. Envía cualquier código que dé **Compilation error** al problema [{problem}]({url})\n\
+        2. Vuelve a usar `{command}`\n\n\
+        Tienes {minutes} minutos, la verificación expira <t:{expires}:R>.",
+    ),
+    (
+        "account.verification_pending",
+        "⏳ Todavía no se encontró un compilation error de `{handle}` en [{problem}]({url}). Envía uno y vuelve a usar `{command}` antes de las <t:{expires}:t>.",
+    ),
+    (
+        "account.verification_unavailable",
+        "❌ No se pudo elegir un problema para la verificación. Intenta de nuevo más tarde.",
+    ),
+    // =====================
+    //   Solved
+    // =====================
+    (
+        "solved.missing_problem",
+        "❌ Por favor proporciona un ID de problema. Uso: `{usage}` (ej. `{prefix}solved 467B`)",
+    ),
+    (
+        "solved.invalid_format",
+        "❌ Formato de problema inválido. Usa el formato: `467B` (contest_id + index) o el enlace del problema",
+    ),
+    (
+        "solved.already_solved",
+        "ℹ️ El problema `{problem}` ya está marcado como resuelto.",
+    ),
+    (
+        "solved.check_error",
+        "❌ Error al verificar el estado del problema. Intenta de nuevo más tarde.",
+    ),
+    (
+        "solved.handle_gone",
+        "❌ El handle `{handle}` ya no existe en Codeforces. Vuelve a vincular tu cuenta con `{prefix}account tu_handle`.",
+    ),
+    (
+        "solved.not_solved",
+        "❌ **Problema no resuelto**\n\n\
+        🔍 No se encontró una solución exitosa para el problema `{problem}`\n\
+        👤 Usuario: `{handle}`\n\n\
+        💡 **Posibles razones:**\n\
+        • El problema no ha sido resuelto aún\n\
+        • La solución no pasó todos los test cases\n\
+        • El problema no existe o el formato es incorrecto\n\n\
+        ¡Sigue intentando! 💪",
+    ),
+    (
+        "solved.save_error",
+        "❌ Error al marcar el problema como resuelto. Intenta de nuevo más tarde.",
+    ),
+    (
+        "solved.verified",
+        "🎉 **¡Problema resuelto verificado!**\n\n\
+        ✅ El problema `{problem}` ha sido marcado como resuelto.\n\
+        👤 Usuario: `{handle}`\n\
+        💰 **+1 moneda ganada!**\n\
+        🏆 ¡Felicitaciones por la solución exitosa!",
+    ),
+    (
+        "solved.daily_bonus",
+        "📅 ¡**+{coins} monedas** extra por resolver el problema diario!",
+    ),
+    // =====================
+    //   Sync
+    // =====================
+    (
+        "sync.imported",
+        "📥 Se importaron **{imported}** problemas resueltos nuevos de `{handle}` ({accepted} aceptados en total)",
+    ),
+    (
+        "sync.imported_coins",
+        "📥 Se importaron **{imported}** problemas resueltos nuevos de `{handle}` ({accepted} aceptados en total)\n🪙 +{coins} monedas",
+    ),
+    ("sync.save_error", "❌ Error al importar tus problemas resueltos. Inténtalo más tarde."),
+    // =====================
+    //   Solves
+    // =====================
+    (
+        "solves.announcement",
+        "🎉 <@{user}> resolvió [{problem} - {name}]({url}) y ganó {coins} moneda(s)",
+    ),
+    // =====================
+    //   Profile
+    // =====================
+    ("profile.title", "👤 Perfil"),
+    ("profile.handle", "🔗 Codeforces"),
+    ("profile.not_linked", "Sin vincular"),
+    ("profile.rank", "🎯 Rango"),
+    ("profile.rating", "🏆 Rating"),
+    ("profile.rating_value", "**{rating}** (máx. {max})"),
+    ("profile.solved", "✅ Problemas verificados"),
+    ("profile.coins", "🪙 Monedas"),
+    ("profile.level", "⭐ Nivel"),
+    ("profile.level_value", "**{level}** ({xp} XP)"),
+    ("profile.linked_at", "📅 Vinculado desde"),
+    ("profile.unknown_date", "Desconocido"),
+    // =====================
+    //   Rating History
+    // =====================
+    (
+        "ratinghistory.no_contests",
+        "ℹ️ `{handle}` todavía no ha participado en concursos con rating.",
+    ),
+    ("ratinghistory.title", "📈 Historial de rating de {handle}"),
+    ("ratinghistory.contests", "🏁 Concursos"),
+    ("ratinghistory.current", "🏆 Rating actual"),
+    ("ratinghistory.current_value", "**{current}** (máx. {max})"),
+    ("ratinghistory.deltas", "📊 Mejor / peor cambio"),
+    ("ratinghistory.recent", "🕒 Últimos concursos"),
+    // =====================
+    //   Problem
+    // =====================
+    (
+        "problem.incorrect_argument",
+        "❌ Argumento incorrecto `{argument}`. Uso: {usage} (ejemplo: {prefix}problem 1200 1600 dp -math unsolved)",
+    ),
+    (
+        "problem.min_above_max",
+        "❌ `min_rating` no puede ser mayor que `max_rating`",
+    ),
+    (
+        "problem.range_error",
+        "❌ Error al leer el rango de rating del servidor",
+    ),
+    (
+        "problem.no_results",
+        "❌ No se encontraron problemas con esos filtros",
+    ),
+    (
+        "problem.unsolved_needs_account",
+        "❌ `unsolved` requiere una cuenta de Codeforces vinculada. Usa `{prefix}account tu_handle` primero",
+    ),
+    (
+        "problem.account_error",
+        "❌ Error al leer tu cuenta vinculada",
+    ),
+    ("problem.title", "💻 Problema de Codeforces"),
+    ("problem.greeting", "¡Hola <@{user}>! Aquí tienes tu problema:"),
+    ("problem.difficulty", "🎯 Dificultad"),
+    ("problem.tags", "🏷️ Etiquetas"),
+    ("problem.link", "🔗 Enlace"),
+    ("problem.solve_link", "[Resolver problema]({url})"),
+    // =====================
+    //   Daily Problem
+    // =====================
+    ("daily.title", "💻 Problema diario de Codeforces"),
+    (
+        "daily.greeting",
+        "¡Hola <@&{role}>! Aquí tienen el problema del día:",
+    ),
+    (
+        "daily.none",
+        "📭 Todavía no se ha publicado ningún problema diario aquí. Un administrador puede configurarlo con `{prefix}setchannel #canal`",
+    ),
+    (
+        "daily.current",
+        "📅 Problema diario actual, publicado <t:{posted}:R> ([publicación original]({url}))",
+    ),
+    (
+        "daily.no_problems",
+        "❌ No se encontraron problemas en ese rango de dificultad.",
+    ),
+    // =====================
+    //   Set Hour
+    // =====================
+    (
+        "sethour.incorrect_format",
+        "❌ Formato incorrecto. Uso: {usage} (ejemplo: {prefix}sethour 09:00)",
+    ),
+    (
+        "sethour.invalid_time",
+        "❌ Hora inválida. Usa el formato HH:MM (00:00 - 23:59)",
+    ),
+    ("sethour.saved", "⏰ Hora del reporte diario configurada a las {time}"),
+    ("sethour.save_error", "❌ Error al guardar la hora"),
+    // =====================
+    //   Set Channel
+    // =====================
+    (
+        "setchannel.incorrect_format",
+        "❌ Formato incorrecto. Uso: {usage}",
+    ),
+    ("setchannel.invalid_channel", "❌ ID de canal inválido"),
+    (
+        "setchannel.saved",
+        "✅ Canal del reporte diario configurado a <#{channel}>",
+    ),
+    ("setchannel.save_error", "❌ Error al guardar el canal"),
+    // =====================
+    //   Set Solved Threshold
+    // =====================
+    (
+        "setsolvedthreshold.incorrect_format",
+        "❌ Formato incorrecto. Usa: {usage}",
+    ),
+    (
+        "setsolvedthreshold.out_of_range",
+        "❌ El umbral debe ser un porcentaje entre 0 y 100",
+    ),
+    (
+        "setsolvedthreshold.saved",
+        "✅ Se evitarán los problemas diarios resueltos por más del **{percent}%** de los miembros vinculados",
+    ),
+    (
+        "setsolvedthreshold.disabled",
+        "✅ Los problemas diarios ya no se evitan por estar resueltos",
+    ),
+    (
+        "setsolvedthreshold.save_error",
+        "❌ Error al guardar el umbral de resueltos",
+    ),
+    // =====================
+    //   Set Solve Channel
+    // =====================
+    ("setsolvechannel.incorrect_format", "❌ Formato incorrecto. Usa: {usage}"),
+    (
+        "setsolvechannel.saved",
+        "✅ Los problemas resueltos se anunciarán en <#{channel}>",
+    ),
+    ("setsolvechannel.disabled", "✅ Anuncios de problemas resueltos desactivados"),
+    ("setsolvechannel.save_error", "❌ Error al guardar el canal de resueltos"),
+    // =====================
+    //   Set Daily Bonus
+    // =====================
+    (
+        "setdailybonus.incorrect_format",
+        "❌ Formato incorrecto. Usa: {usage}",
+    ),
+    (
+        "setdailybonus.out_of_range",
+        "❌ La bonificación debe estar entre 0 y {max} monedas",
+    ),
+    (
+        "setdailybonus.saved",
+        "✅ Resolver el problema diario ahora otorga **{coins}** monedas extra",
+    ),
+    (
+        "setdailybonus.disabled",
+        "✅ Bonificación del problema diario desactivada",
+    ),
+    (
+        "setdailybonus.save_error",
+        "❌ Error al guardar la bonificación diaria",
+    ),
+    // =====================
+    //   Set Sync Reward
+    // =====================
+    (
+        "setsyncreward.incorrect_format",
+        "❌ Formato incorrecto. Usa: {usage}",
+    ),
+    (
+        "setsyncreward.enabled",
+        "✅ Los problemas importados con sync otorgarán monedas",
+    ),
+    (
+        "setsyncreward.disabled",
+        "✅ Los problemas importados con sync ya no otorgarán monedas",
+    ),
+    (
+        "setsyncreward.save_error",
+        "❌ Error al guardar la configuración de recompensas de sync",
+    ),
+    // =====================
+    //   Set Rating
+    // =====================
+    (
+        "setrating.missing_bound",
+        "❌ Indica `min_rating` y `max_rating`",
+    ),
+    (
+        "setrating.incorrect_format",
+        "❌ Formato incorrecto. Uso: {usage} (ejemplo: {prefix}setrating 800 1400)",
+    ),
+    (
+        "setrating.out_of_range",
+        "❌ `{rating}` está fuera de rango. Los ratings van de {min} a {max}",
+    ),
+    (
+        "setrating.not_multiple",
+        "❌ `{rating}` no es un rating de problema válido. Los ratings son múltiplos de 100",
+    ),
+    (
+        "setrating.min_above_max",
+        "❌ El rating mínimo ({min}) no puede ser mayor que el máximo ({max})",
+    ),
+    (
+        "setrating.current",
+        "🎯 Los problemas diarios tienen rating **{min} - {max}**. Cámbialo con `{usage}`",
+    ),
+    (
+        "setrating.read_error",
+        "❌ Error al leer el rango de rating",
+    ),
+    (
+        "setrating.saved",
+        "✅ Los problemas diarios tendrán rating entre **{min}** y **{max}**",
+    ),
+    (
+        "setrating.save_error",
+        "❌ Error al guardar el rango de rating",
+    ),
+    // =====================
+    //   Set Admin Role
+    // =====================
+    (
+        "setadminrole.incorrect_format",
+        "❌ Formato incorrecto. Uso: {usage}",
+    ),
+    ("setadminrole.invalid_role", "❌ ID de rol inválido"),
+    (
+        "setadminrole.saved",
+        "✅ Los miembros con <@&{role}> ahora pueden configurar el bot",
+    ),
+    (
+        "setadminrole.removed",
+        "✅ Rol de administrador del bot eliminado, solo los miembros con Gestionar servidor pueden configurar el bot",
+    ),
+    (
+        "setadminrole.save_error",
+        "❌ Error al guardar el rol de administrador",
+    ),
+    // =====================
+    //   Set Language
+    // =====================
+    (
+        "setlanguage.current",
+        "🌐 El bot responde en **{language}** aquí. Cámbialo con `{usage}` ({languages})",
+    ),
+    (
+        "setlanguage.unknown",
+        "❌ Idioma desconocido `{language}`. Idiomas disponibles: {languages}",
+    ),
+    (
+        "setlanguage.saved",
+        "✅ El bot ahora responderá en **{language}**",
+    ),
+    ("setlanguage.save_error", "❌ Error al guardar el idioma"),
+    // =====================
+    //   Config
+    // =====================
+    (
+        "config.not_configured",
+        "❌ Este servidor todavía no tiene configuración",
+    ),
+    (
+        "config.read_error",
+        "❌ Error al leer la configuración del servidor",
+    ),
+    ("config.title", "⚙️ Configuración del servidor"),
+    ("config.prefix", "🔤 Prefijo"),
+    ("config.language", "🌐 Idioma"),
+    ("config.admin_role", "🛡️ Rol de administrador"),
+    (
+        "config.admin_unset",
+        "Sin configurar (solo Gestionar servidor)",
+    ),
+    ("config.channel", "📢 Canal diario"),
+    (
+        "config.channel_unset",
+        "Sin configurar (usa `{prefix}setchannel #canal`)",
+    ),
+    ("config.solve_channel", "🎉 Anuncios de resueltos"),
+    (
+        "config.solve_channel_unset",
+        "Desactivados (usa `{prefix}setsolvechannel #canal`)",
+    ),
+    ("config.sync_reward", "🪙 Monedas por resueltos importados"),
+    ("config.sync_reward_on", "Activadas"),
+    ("config.sync_reward_off", "Desactivadas"),
+    ("config.daily_bonus", "📅 Bonificación diaria"),
+    ("config.daily_bonus_value", "{coins} monedas"),
+    ("config.solved_threshold", "🚫 Evitar diarios muy resueltos"),
+    ("config.solved_threshold_value", "Resueltos por más del {percent}%"),
+    ("config.solved_threshold_off", "Desactivado"),
+    ("config.time", "⏰ Hora diaria"),
+    ("config.time_value", "{time} (hora del bot)"),
+    ("config.rating", "🎯 Rango de rating diario"),
+];
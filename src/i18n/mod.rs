@@ -0,0 +1,163 @@
+// =====================
+//   Localization Module
+// =====================
+
+use std::fmt::Display;
+
+// Message catalogs, one per supported language
+mod en; // English messages
+mod es; // Spanish messages
+
+// =====================
+//   Languages
+// =====================
+
+// Languages a guild can choose for the bot's replies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+}
+
+impl Language {
+    // Every supported language, in the order they are listed to users
+    pub const ALL: [Language; 2] = [Language::En, Language::Es];
+
+    // Code stored in `guild_config.language` and typed by users
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+        }
+    }
+
+    // Name of the language in itself
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Español",
+        }
+    }
+
+    // Parse a language code (`en`, `ES`)
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code.trim()))
+    }
+
+    // Discord locales whose users should see this language in slash command descriptions
+    pub fn discord_locales(self) -> &'static [&'static str] {
+        match self {
+            Language::En => &["en-US", "en-GB"],
+            Language::Es => &["es-ES", "es-419"],
+        }
+    }
+
+    // Key and template of every message in this language
+    fn messages(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::En => en::MESSAGES,
+            Language::Es => es::MESSAGES,
+        }
+    }
+}
+
+// =====================
+//   Message Lookup
+// =====================
+
+// Template of a message, if the language defines it
+pub fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    language
+        .messages()
+        .iter()
+        .find(|(message_key, _)| *message_key == key)
+        .map(|(_, template)| *template)
+}
+
+// Message without placeholders; a missing key shows up as the key itself
+pub fn tr(language: Language, key: &str) -> &'static str {
+    match lookup(language, key) {
+        Some(template) => template,
+        None => {
+            eprintln!("Missing {} message: {}", language.code(), key);
+            lookup(Language::default(), key).unwrap_or("❌ ???")
+        }
+    }
+}
+
+// Message with its `{name}` placeholders filled in
+pub fn tr_args(language: Language, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(tr(language, key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    // Placeholder names used by a template (`{handle}`)
+    fn placeholders(template: &str) -> BTreeSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}'))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn every_key_exists_in_every_language() {
+        let reference = Language::default().messages();
+
+        for language in Language::ALL {
+            let keys: BTreeSet<&str> = language.messages().iter().map(|(key, _)| *key).collect();
+            assert_eq!(
+                keys.len(),
+                language.messages().len(),
+                "{} defines a key twice",
+                language.code()
+            );
+
+            for (key, template) in reference {
+                let translated = lookup(language, key)
+                    .unwrap_or_else(|| panic!("{} is missing {}", language.code(), key));
+                assert_eq!(
+                    placeholders(template),
+                    placeholders(translated),
+                    "{} uses other placeholders in {}",
+                    language.code(),
+                    key
+                );
+            }
+
+            for key in keys {
+                assert!(
+                    lookup(Language::default(), key).is_some(),
+                    "{} defines unknown key {}",
+                    language.code(),
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fills_placeholders_and_parses_codes() {
+        assert_eq!(
+            tr_args(Language::En, "sethour.saved", &[("time", &"09:30")]),
+            "⏰ Daily report time set to 09:30"
+        );
+        assert_eq!(Language::from_code(" ES "), Some(Language::Es));
+        assert_eq!(Language::from_code("fr"), None);
+    }
+}
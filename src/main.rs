@@ -9,12 +9,19 @@ use serenity::model::prelude::*;
 use serenity::prelude::*;
 use sqlx::PgPool;
 use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+use api::codeforces::CodeforcesClient;
+use api::problem_cache::ProblemCache;
+use settings::GuildSettingsCache;
+
 // import Modules
 mod api;
 mod commands;
 mod events;
+mod i18n;
+mod settings;
 mod tasks;
 
 // =====================
@@ -24,6 +31,9 @@ mod tasks;
 // Define principal bot
 pub struct Bot {
     pub db: PgPool,
+    pub codeforces: CodeforcesClient,
+    pub problem_cache: ProblemCache,
+    pub settings: GuildSettingsCache,
 }
 
 // =====================
@@ -33,6 +43,8 @@ pub struct Bot {
 // Define the event handler struct
 struct Handler {
     bot: Arc<Bot>,
+    // Set once the slash commands have been registered
+    commands_registered: AtomicBool,
 }
 
 // Implement the EventHandler trait for the Handler struct
@@ -45,20 +57,22 @@ impl EventHandler for Handler {
             ready.user.name, ready.user.id
         );
 
-        let bot = Arc::clone(&self.bot);
-        let ctx_http = Arc::clone(&ctx.http);
-
-        // Start daily task in background
-        tokio::spawn(async move {
-            if let Err(e) = tasks::daily::start_daily_task(bot, ctx_http).await {
-                eprintln!("Daily task error: {}", e);
-            }
-        });
+        // Register slash commands so they show up in Discord. `ready` runs again on every
+        // new gateway session, so only the first one registers them.
+        if !self.commands_registered.swap(true, Ordering::SeqCst) {
+            events::interaction::register_commands(&ctx.http).await;
+        }
     }
 
     // Handle incoming messages
     async fn message(&self, ctx: Context, msg: Message) {
-        events::message::handle_message(&self.bot, &ctx.http, msg).await;
+        events::message::handle_message(&self.bot, &ctx.http, &ctx.cache, msg).await;
+    }
+
+    // Handle slash commands and autocomplete
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        events::interaction::handle_interaction(&self.bot, &ctx.http, &ctx.cache, interaction)
+            .await;
     }
 
     // Handle guild creation/joining
@@ -114,12 +128,54 @@ async fn main() -> anyhow::Result<()> {
             daily_minute INT,
             min_rating INT DEFAULT 800,
             max_rating INT DEFAULT 1200,
-            level_system_enabled BOOLEAN DEFAULT true
+            level_system_enabled BOOLEAN DEFAULT true,
+            admin_role_id BIGINT,
+            language TEXT DEFAULT 'en',
+            solve_channel_id BIGINT,
+            sync_awards_coins BOOLEAN DEFAULT false,
+            daily_bonus INT DEFAULT 3,
+            daily_solved_threshold INT
         )",
     )
     .execute(&db)
     .await?;
 
+    // Add admin_role_id to guild_config tables created before the bot admin role existed
+    let _ = sqlx::query("ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS admin_role_id BIGINT")
+        .execute(&db)
+        .await?;
+
+    // Add language to guild_config tables created before replies were localized
+    let _ =
+        sqlx::query("ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS language TEXT DEFAULT 'en'")
+            .execute(&db)
+            .await?;
+
+    // Add solve_channel_id to guild_config tables created before solves were announced
+    let _ =
+        sqlx::query("ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS solve_channel_id BIGINT")
+            .execute(&db)
+            .await?;
+
+    // Add sync_awards_coins to guild_config tables created before !sync existed
+    let _ = sqlx::query(
+        "ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS sync_awards_coins BOOLEAN DEFAULT false",
+    )
+    .execute(&db)
+    .await?;
+
+    // Add daily_bonus to guild_config tables created before the daily solve bonus existed
+    let _ =
+        sqlx::query("ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS daily_bonus INT DEFAULT 3")
+            .execute(&db)
+            .await?;
+
+    // Add daily_solved_threshold to guild_config tables created before solved dailies were skipped
+    let _ =
+        sqlx::query("ALTER TABLE guild_config ADD COLUMN IF NOT EXISTS daily_solved_threshold INT")
+            .execute(&db)
+            .await?;
+
     // Create user configuration table if it doesn't exist
     let _ = sqlx::query(
         "CREATE TABLE IF NOT EXISTS user_info (
@@ -132,6 +188,33 @@ async fn main() -> anyhow::Result<()> {
             codeforces_handle TEXT,
             codeforces_rating INT DEFAULT 0,
             codeforces_rank TEXT DEFAULT 'unrated',
+            codeforces_max_rating INT DEFAULT 0,
+            linked_at TIMESTAMPTZ,
+            primary key (guild_id, user_id),
+            foreign key (guild_id) references guild_config(guild_id)
+        )",
+    )
+    .execute(&db)
+    .await?;
+
+    // Add the profile columns to user_info tables created before !profile existed
+    let _ = sqlx::query(
+        "ALTER TABLE user_info
+        ADD COLUMN IF NOT EXISTS codeforces_max_rating INT DEFAULT 0,
+        ADD COLUMN IF NOT EXISTS linked_at TIMESTAMPTZ",
+    )
+    .execute(&db)
+    .await?;
+
+    // Create pending_verification table if it doesn't exist
+    let _ = sqlx::query(
+        "CREATE TABLE IF NOT EXISTS pending_verification (
+            guild_id BIGINT,
+            user_id BIGINT,
+            codeforces_handle TEXT NOT NULL,
+            problem_id TEXT NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            expires_at TIMESTAMPTZ NOT NULL,
             primary key (guild_id, user_id),
             foreign key (guild_id) references guild_config(guild_id)
         )",
@@ -152,27 +235,148 @@ async fn main() -> anyhow::Result<()> {
     .execute(&db)
     .await?;
 
+    // Normalize stored problem ids to their canonical form (467b -> 467B),
+    // dropping rows that only differed by case
+    let _ = sqlx::query(
+        "DELETE FROM user_solved_problem a
+        USING user_solved_problem b
+        WHERE a.guild_id = b.guild_id
+        AND a.user_id = b.user_id
+        AND UPPER(TRIM(a.problem_id)) = UPPER(TRIM(b.problem_id))
+        AND a.problem_id <> UPPER(TRIM(a.problem_id))
+        AND (b.problem_id = UPPER(TRIM(b.problem_id)) OR a.ctid > b.ctid)",
+    )
+    .execute(&db)
+    .await?;
+
+    let _ = sqlx::query(
+        "UPDATE user_solved_problem
+        SET problem_id = UPPER(TRIM(problem_id))
+        WHERE problem_id <> UPPER(TRIM(problem_id))",
+    )
+    .execute(&db)
+    .await?;
+
+    // Create daily_problems table (one posted daily problem per guild and day) if it doesn't exist
+    let _ = sqlx::query(
+        "CREATE TABLE IF NOT EXISTS daily_problems (
+            guild_id BIGINT,
+            posted_on DATE,
+            problem_id TEXT NOT NULL,
+            channel_id BIGINT NOT NULL,
+            message_id BIGINT NOT NULL,
+            posted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            primary key (guild_id, posted_on),
+            foreign key (guild_id) references guild_config(guild_id)
+        )",
+    )
+    .execute(&db)
+    .await?;
+
+    // Create submission_cursor table (newest submission seen per lowercased handle) if it doesn't exist
+    let _ = sqlx::query(
+        "CREATE TABLE IF NOT EXISTS submission_cursor (
+            codeforces_handle TEXT PRIMARY KEY,
+            last_submission_id BIGINT NOT NULL
+        )",
+    )
+    .execute(&db)
+    .await?;
+
+    // Create cf_problems table (persisted problemset cache) if it doesn't exist
+    let _ = sqlx::query(
+        "CREATE TABLE IF NOT EXISTS cf_problems (
+            contest_id INT NOT NULL,
+            problem_index TEXT NOT NULL,
+            name TEXT NOT NULL,
+            problem_type TEXT NOT NULL,
+            points REAL,
+            rating INT,
+            tags TEXT[] NOT NULL DEFAULT '{}',
+            solved_count INT,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            primary key (contest_id, problem_index)
+        )",
+    )
+    .execute(&db)
+    .await?;
+
+    // Add solved_count to cf_problems tables created before problem statistics were stored
+    let _ = sqlx::query("ALTER TABLE cf_problems ADD COLUMN IF NOT EXISTS solved_count INT")
+        .execute(&db)
+        .await?;
+
+    // =====================
+    //   Codeforces Client Setup
+    // =====================
+
+    // Build the shared Codeforces API client (configured via CODEFORCES_* variables)
+    let codeforces = CodeforcesClient::from_env()?;
+
+    // Build the problemset cache and warm it from the database so restarts don't re-download
+    let problem_cache = ProblemCache::from_env(codeforces.clone(), db.clone());
+    match problem_cache.load_from_db().await {
+        Ok(count) => println!("Loaded {} cached problems from database ✅", count),
+        Err(e) => println!("Error loading cached problems: {:?}", e),
+    }
+
     // =====================
     //   Bot Instance Creation
     // =====================
 
     // Initialize the bot struct
-    let bot = Arc::new(Bot { db });
+    let bot = Arc::new(Bot {
+        settings: GuildSettingsCache::new(db.clone()),
+        db,
+        codeforces,
+        problem_cache,
+    });
+
+    // Keep the problemset cache fresh in the background
+    let cache_bot = Arc::clone(&bot);
+    tokio::spawn(tasks::problem_cache::start_problem_cache_task(cache_bot));
+
+    // Refresh linked members' ratings in batches
+    let ratings_bot = Arc::clone(&bot);
+    tokio::spawn(tasks::ratings::start_rating_refresh_task(ratings_bot));
 
     // =====================
     //   Discord Client Setup
     // =====================
 
     // Set up the Discord client with intents
+    // (MESSAGE_CONTENT is only needed for prefix commands; slash commands work without it.
+    // DIRECT_MESSAGES lets the bot answer server-only commands sent in DMs with a refusal.)
     let intents = GatewayIntents::GUILDS
         | GatewayIntents::GUILD_MEMBERS
         | GatewayIntents::GUILD_MESSAGES
+        | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT;
 
+    let solves_bot = Arc::clone(&bot);
+    let daily_bot = Arc::clone(&bot);
     let mut client = Client::builder(&token, intents)
-        .event_handler(Handler { bot })
+        .event_handler(Handler {
+            bot,
+            commands_registered: AtomicBool::new(false),
+        })
         .await?;
 
+    // Detect solves of linked handles in the background. Spawned here rather than in
+    // `ready`, which runs again on every new gateway session.
+    tokio::spawn(tasks::solves::start_solve_polling_task(
+        solves_bot,
+        Arc::clone(&client.http),
+    ));
+
+    // Post the daily problems in the background (started once for the same reason)
+    let daily_http = Arc::clone(&client.http);
+    tokio::spawn(async move {
+        if let Err(e) = tasks::daily::start_daily_task(daily_bot, daily_http).await {
+            eprintln!("Daily task error: {}", e);
+        }
+    });
+
     // =====================
     //   Bot Startup
     // =====================
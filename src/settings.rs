@@ -0,0 +1,118 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::i18n::Language;
+use serenity::model::id::{GuildId, RoleId};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+// Prefix used in DMs and by guilds that never configured one
+pub const DEFAULT_PREFIX: &str = "!";
+
+// =====================
+//   Guild Settings
+// =====================
+
+// Per-guild settings read on every command, cached from `guild_config`
+#[derive(Debug, Clone)]
+pub struct GuildSettings {
+    pub prefix: String,
+    // Role allowed to run configuration commands besides Manage Server
+    pub admin_role_id: Option<RoleId>,
+    // Language of the bot's replies
+    pub language: Language,
+}
+
+impl Default for GuildSettings {
+    fn default() -> Self {
+        Self {
+            prefix: DEFAULT_PREFIX.to_string(),
+            admin_role_id: None,
+            language: Language::default(),
+        }
+    }
+}
+
+// =====================
+//   Settings Cache
+// =====================
+
+// Keeps guild settings in memory so dispatching a message doesn't hit the database.
+// Commands that change `guild_config` must call `invalidate` afterwards.
+pub struct GuildSettingsCache {
+    db: PgPool,
+    entries: RwLock<HashMap<GuildId, GuildSettings>>,
+}
+
+impl GuildSettingsCache {
+    // Create an empty cache
+    pub fn new(db: PgPool) -> Self {
+        Self {
+            db,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Settings for a guild, or the defaults outside of a guild
+    pub async fn get(&self, guild_id: Option<GuildId>) -> GuildSettings {
+        let Some(guild_id) = guild_id else {
+            return GuildSettings::default();
+        };
+
+        if let Some(settings) = self.entries.read().await.get(&guild_id) {
+            return settings.clone();
+        }
+
+        match self.load(guild_id).await {
+            Ok(settings) => {
+                self.entries
+                    .write()
+                    .await
+                    .insert(guild_id, settings.clone());
+                settings
+            }
+            Err(e) => {
+                // Not cached, so the next message tries the database again
+                eprintln!("Error loading settings for guild {}: {}", guild_id, e);
+                GuildSettings::default()
+            }
+        }
+    }
+
+    // Drop a guild's cached settings so the next read sees the database
+    pub async fn invalidate(&self, guild_id: GuildId) {
+        self.entries.write().await.remove(&guild_id);
+    }
+
+    // Read a guild's settings from `guild_config`
+    async fn load(&self, guild_id: GuildId) -> Result<GuildSettings, sqlx::Error> {
+        let row = sqlx::query_as::<_, (Option<String>, Option<i64>, Option<String>)>(
+            "SELECT guild_prefix, admin_role_id, language FROM guild_config WHERE guild_id = $1",
+        )
+        .bind(guild_id.get() as i64)
+        .fetch_optional(&self.db)
+        .await?;
+
+        let (prefix, admin_role_id, language) = row.unwrap_or_default();
+
+        // A missing or blank prefix falls back to the default
+        let prefix = prefix
+            .map(|prefix| prefix.trim().to_string())
+            .filter(|prefix| !prefix.is_empty())
+            .unwrap_or_else(|| DEFAULT_PREFIX.to_string());
+
+        Ok(GuildSettings {
+            prefix,
+            admin_role_id: admin_role_id
+                .filter(|id| *id > 0)
+                .map(|id| RoleId::new(id as u64)),
+            // Unknown codes (a language that was removed) fall back to the default
+            language: language
+                .as_deref()
+                .and_then(Language::from_code)
+                .unwrap_or_default(),
+        })
+    }
+}
@@ -2,22 +2,33 @@
 //   Imports and Dependencies
 // =====================
 
-use crate::api;
+use crate::api::codeforces::{Problem, ProblemFilter};
+use crate::api::problem_id::ProblemId;
+use crate::commands::codeforces::problem_embed;
+use crate::i18n::{tr, tr_args, Language};
 use crate::Bot;
-use chrono::{Local, Timelike};
-use rand::seq::SliceRandom;
+use chrono::{DateTime, Local, Timelike, Utc};
+use rand::seq::IndexedRandom;
+use serenity::builder::CreateEmbed;
 use serenity::http::Http;
 use serenity::model::id::ChannelId;
-use sqlx::Row;
+use sqlx::{PgPool, Row};
+use std::collections::HashSet;
 use std::error::Error;
 use std::sync::Arc;
 
+// Defaults for guilds that never configured the daily problem
+pub const DEFAULT_DAILY_HOUR: i32 = 9;
+pub const DEFAULT_DAILY_MINUTE: i32 = 0;
+pub const DEFAULT_MIN_RATING: i32 = 800;
+pub const DEFAULT_MAX_RATING: i32 = 1200;
+pub const DEFAULT_DAILY_BONUS: i32 = 3;
+
 // =====================
 //   Daily Task Implementation
 // =====================
 
 // Main daily task function - runs continuously to check for scheduled problem postings
-#[allow(deprecated)]
 pub async fn start_daily_task(
     bot: Arc<Bot>,
     http: Arc<Http>,
@@ -50,7 +61,8 @@ pub async fn start_daily_task(
 
         // Fetch all guild configurations that have a daily channel configured
         let server_result = sqlx::query(
-            "SELECT guild_id, daily_channel_id, daily_hour, daily_minute, min_rating, max_rating
+            "SELECT guild_id, daily_channel_id, daily_hour, daily_minute, min_rating, max_rating, language,
+                daily_solved_threshold
             FROM guild_config
             WHERE daily_channel_id IS NOT NULL",
         )
@@ -81,11 +93,11 @@ pub async fn start_daily_task(
             let scheduled_hour = server
                 .try_get::<Option<i32>, _>("daily_hour")
                 .unwrap_or_default()
-                .unwrap_or(9);
+                .unwrap_or(DEFAULT_DAILY_HOUR);
             let scheduled_minute = server
                 .try_get::<Option<i32>, _>("daily_minute")
                 .unwrap_or_default()
-                .unwrap_or(0);
+                .unwrap_or(DEFAULT_DAILY_MINUTE);
 
             // Check if current time matches scheduled time
             if actual_hour == scheduled_hour && actual_minute == scheduled_minute {
@@ -100,35 +112,63 @@ pub async fn start_daily_task(
                 };
                 let guild_id = server.try_get::<i64, _>("guild_id").unwrap_or(0);
 
+                // Post at most one daily per day; a second one would move `!daily` and the bonus
+                match daily_posted_today(&bot.db, guild_id).await {
+                    Ok(false) => {}
+                    Ok(true) => continue,
+                    Err(e) => {
+                        println!(
+                            "Error checking today's daily problem of guild {}: {}",
+                            guild_id, e
+                        );
+                        continue;
+                    }
+                }
+
+                // Post in the guild's language
+                let language = server
+                    .try_get::<Option<String>, _>("language")
+                    .unwrap_or_default()
+                    .as_deref()
+                    .and_then(Language::from_code)
+                    .unwrap_or_default();
+
                 // Get rating range from server config with defaults
                 let min_rating = server
                     .try_get::<Option<i32>, _>("min_rating")
                     .unwrap_or_default()
-                    .unwrap_or(800);
+                    .unwrap_or(DEFAULT_MIN_RATING);
                 let max_rating = server
                     .try_get::<Option<i32>, _>("max_rating")
                     .unwrap_or_default()
-                    .unwrap_or(1200);
+                    .unwrap_or(DEFAULT_MAX_RATING);
+
+                // Share of linked members (in percent) above which a solved problem is skipped
+                let solved_threshold = server
+                    .try_get::<Option<i32>, _>("daily_solved_threshold")
+                    .unwrap_or_default();
 
                 // =====================
                 //   Codeforces API Integration
                 // =====================
 
-                // Fetch problems from Codeforces API with server's rating range
-                let problems_result =
-                    api::codeforces::get_problems(Some(min_rating as u32), Some(max_rating as u32))
-                        .await;
+                // Get cached problems within the server's rating range
+                let problems_result = bot
+                    .problem_cache
+                    .problems_matching(&ProblemFilter::rating_range(
+                        Some(min_rating as u32),
+                        Some(max_rating as u32),
+                    ))
+                    .await;
 
                 match problems_result {
-                    Ok(problems_data) => {
+                    Ok(problems) => {
                         // =====================
                         //   Problem Processing and Filtering
                         // =====================
 
-                        let problems = problems_data.result.problems;
-
                         // Collect and filter rated problems
-                        let mut filtered_problems: Vec<_> = problems
+                        let filtered_problems: Vec<_> = problems
                             .into_iter()
                             .filter(|p| p.rating.is_some())
                             .collect();
@@ -140,7 +180,7 @@ pub async fn start_daily_task(
 
                             // No problem found in the desired range
                             let message = serenity::builder::CreateMessage::new()
-                                .content("❌ No problems found in that difficulty range.");
+                                .content(tr(language, "daily.no_problems"));
                             if let Err(why) = ChannelId::new(channel_id as u64)
                                 .send_message(&http, message)
                                 .await
@@ -155,78 +195,38 @@ pub async fn start_daily_task(
                             //   Random Problem Selection
                             // =====================
 
-                            // Randomly shuffle and get index in a separate scope
-                            let problem_index = {
-                                let mut rng = rand::thread_rng();
-                                filtered_problems.shuffle(&mut rng);
-                                0
+                            // Avoid repeats and problems most members already solved
+                            let exclusions =
+                                match daily_exclusions(&bot.db, guild_id, solved_threshold).await {
+                                    Ok(exclusions) => exclusions,
+                                    Err(e) => {
+                                        println!(
+                                            "Error reading daily exclusions of guild {}: {}",
+                                            guild_id, e
+                                        );
+                                        DailyExclusions::default()
+                                    }
+                                };
+                            let candidates = daily_candidates(&filtered_problems, &exclusions);
+
+                            // The RNG is a temporary, dropped before any await
+                            let Some(problem) = candidates.choose(&mut rand::rng()).copied() else {
+                                continue;
                             };
-                            // RNG is now dropped here, before any await
-
-                            let problem = &filtered_problems[problem_index];
-
-                            // =====================
-                            //   Difficulty Visualization
-                            // =====================
-
-                            // Create difficulty bars based on rating
-                            let rating = problem.rating.unwrap_or(0);
-                            let difficulty_bars = match rating {
-                                r if r <= 1000 => "🟩".repeat(1), // Beginner
-                                r if r <= 1400 => "🟩".repeat(2), // Easy
-                                r if r <= 1800 => "🟩".repeat(3), // Medium
-                                r if r <= 2200 => "🟩".repeat(4), // Hard
-                                _ => "🟩".repeat(5),              // Expert
-                            };
-
-                            // =====================
-                            //   Problem Information Formatting
-                            // =====================
-
-                            // Format tags for display
-                            let tags_text = if problem.tags.is_empty() {
-                                "No tags".to_string()
-                            } else {
-                                problem
-                                    .tags
-                                    .iter()
-                                    .map(|tag| format!("`{}`", tag))
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            };
-
-                            // Generate problem URL
-                            let problem_url = format!(
-                                "https://codeforces.com/problemset/problem/{}/{}",
-                                problem.contest_id.unwrap_or(0),
-                                problem.index
-                            );
 
                             // =====================
                             //   Discord Message Creation
                             // =====================
 
                             // Create rich embed with problem information
-                            let embed = serenity::builder::CreateEmbed::new()
-                                .title("💻 Daily Codeforces Problem")
-                                .description(format!("**{}**", problem.name))
-                                .field(
-                                    "🎯 Difficulty",
-                                    format!("{} **{}**", difficulty_bars, rating),
-                                    true,
-                                )
-                                .field("🏷️ Tags", tags_text, false)
-                                .field(
-                                    "🔗 Link",
-                                    format!("[Solve problem]({})", problem_url),
-                                    false,
-                                );
+                            let embed = daily_embed(language, problem);
 
                             // Create message with guild mention and embed
                             let message = serenity::builder::CreateMessage::new()
-                                .content(format!(
-                                    "Hello <@&{}>! Here's your daily problem:",
-                                    guild_id
+                                .content(tr_args(
+                                    language,
+                                    "daily.greeting",
+                                    &[("role", &guild_id)],
                                 ))
                                 .embed(embed);
 
@@ -235,23 +235,44 @@ pub async fn start_daily_task(
                             // =====================
 
                             // Send daily problem message to configured channel
-                            if let Err(why) = ChannelId::new(channel_id as u64)
+                            match ChannelId::new(channel_id as u64)
                                 .send_message(&http, message)
                                 .await
                             {
-                                println!(
-                                    "Error sending daily message to guild {}: {:?}",
-                                    guild_id, why
-                                );
+                                Ok(sent) => {
+                                    // Remember the post for `!daily` and the daily solve bonus
+                                    if let Some(problem_id) = problem.id() {
+                                        if let Err(e) = record_daily(
+                                            &bot.db,
+                                            guild_id,
+                                            &problem_id,
+                                            channel_id,
+                                            sent.id.get() as i64,
+                                        )
+                                        .await
+                                        {
+                                            println!(
+                                                "Error recording daily problem of guild {}: {}",
+                                                guild_id, e
+                                            );
+                                        }
+                                    }
+                                }
+                                Err(why) => {
+                                    println!(
+                                        "Error sending daily message to guild {}: {:?}",
+                                        guild_id, why
+                                    );
+                                }
                             }
                         }
                     }
-                    Err(_) => {
+                    Err(e) => {
                         // =====================
                         //   API Error Handling
                         // =====================
 
-                        println!("Error fetching problems from Codeforces API");
+                        println!("Error fetching problems from cache: {}", e);
                     }
                 }
             }
@@ -265,3 +286,319 @@ pub async fn start_daily_task(
         tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
     }
 }
+
+// =====================
+//   Daily Problem Embed
+// =====================
+
+// Embed presenting a daily problem, shared by the scheduled post and `!daily`
+pub fn daily_embed(language: Language, problem: &Problem) -> CreateEmbed {
+    problem_embed(language, tr(language, "daily.title"), problem)
+}
+
+// =====================
+//   Daily Problem Selection
+// =====================
+
+// Problems the daily picker should avoid in a guild, by problem id
+#[derive(Debug, Default)]
+pub struct DailyExclusions {
+    // Problems already posted to the guild
+    pub posted: HashSet<String>,
+    // Problems solved by more linked members than the guild's threshold allows
+    pub widely_solved: HashSet<String>,
+}
+
+// Load what the daily picker should skip; `solved_threshold` is a percentage of linked members
+async fn daily_exclusions(
+    db: &PgPool,
+    guild_id: i64,
+    solved_threshold: Option<i32>,
+) -> Result<DailyExclusions, sqlx::Error> {
+    let posted = sqlx::query_scalar::<_, String>(
+        "SELECT problem_id FROM daily_problems WHERE guild_id = $1",
+    )
+    .bind(guild_id)
+    .fetch_all(db)
+    .await?;
+
+    let widely_solved = match solved_threshold {
+        Some(threshold) => {
+            sqlx::query_scalar::<_, String>(
+                "SELECT s.problem_id
+                FROM user_solved_problem s
+                JOIN user_info u ON u.guild_id = s.guild_id AND u.user_id = s.user_id
+                WHERE s.guild_id = $1 AND u.codeforces_handle IS NOT NULL
+                GROUP BY s.problem_id
+                HAVING COUNT(*) * 100 > $2 * (
+                    SELECT COUNT(*) FROM user_info
+                    WHERE guild_id = $1 AND codeforces_handle IS NOT NULL
+                )",
+            )
+            .bind(guild_id)
+            .bind(i64::from(threshold))
+            .fetch_all(db)
+            .await?
+        }
+        None => Vec::new(),
+    };
+
+    Ok(DailyExclusions {
+        posted: posted.into_iter().collect(),
+        widely_solved: widely_solved.into_iter().collect(),
+    })
+}
+
+// Problems the daily can be picked from, relaxing the exclusions when they leave nothing:
+// first fresh and not widely solved, then just fresh, then the whole range again
+fn daily_candidates<'a>(problems: &'a [Problem], exclusions: &DailyExclusions) -> Vec<&'a Problem> {
+    let id = |problem: &Problem| problem.id().map(|id| id.to_string()).unwrap_or_default();
+
+    let fresh: Vec<&Problem> = problems
+        .iter()
+        .filter(|problem| !exclusions.posted.contains(&id(problem)))
+        .collect();
+
+    let preferred: Vec<&Problem> = fresh
+        .iter()
+        .copied()
+        .filter(|problem| !exclusions.widely_solved.contains(&id(problem)))
+        .collect();
+
+    if !preferred.is_empty() {
+        preferred
+    } else if !fresh.is_empty() {
+        fresh
+    } else {
+        problems.iter().collect()
+    }
+}
+
+// =====================
+//   Daily Problem History
+// =====================
+
+// A daily problem posted to a guild
+#[derive(Debug, Clone)]
+pub struct DailyProblem {
+    pub problem_id: ProblemId,
+    pub channel_id: i64,
+    pub message_id: i64,
+    pub posted_at: DateTime<Utc>,
+}
+
+// When a problem was the guild's daily: from its post until the next daily (unix seconds)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DailyWindow {
+    posted_at: i64,
+    replaced_at: Option<i64>,
+}
+
+impl DailyWindow {
+    // A submission made at the next post already belongs to the next daily
+    fn contains(&self, at: i64) -> bool {
+        at >= self.posted_at && self.replaced_at.is_none_or(|replaced_at| at < replaced_at)
+    }
+}
+
+// Whether the guild's daily problem was already posted today
+async fn daily_posted_today(db: &PgPool, guild_id: i64) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM daily_problems WHERE guild_id = $1 AND posted_on = $2)",
+    )
+    .bind(guild_id)
+    .bind(Local::now().date_naive())
+    .fetch_one(db)
+    .await
+}
+
+// Store today's daily problem of a guild (the first post of the day is kept)
+async fn record_daily(
+    db: &PgPool,
+    guild_id: i64,
+    problem_id: &ProblemId,
+    channel_id: i64,
+    message_id: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO daily_problems (guild_id, posted_on, problem_id, channel_id, message_id)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (guild_id, posted_on) DO NOTHING",
+    )
+    .bind(guild_id)
+    .bind(Local::now().date_naive())
+    .bind(problem_id.to_string())
+    .bind(channel_id)
+    .bind(message_id)
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+// The guild's current daily problem: the last one posted, until the next replaces it
+pub async fn current_daily(
+    db: &PgPool,
+    guild_id: i64,
+) -> Result<Option<DailyProblem>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT problem_id, channel_id, message_id, posted_at
+        FROM daily_problems
+        WHERE guild_id = $1
+        ORDER BY posted_at DESC
+        LIMIT 1",
+    )
+    .bind(guild_id)
+    .fetch_optional(db)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    // Rows are written from parsed ids, so this only skips hand-edited data
+    let problem_id = match row.try_get::<String, _>("problem_id")?.parse() {
+        Ok(problem_id) => problem_id,
+        Err(_) => return Ok(None),
+    };
+
+    Ok(Some(DailyProblem {
+        problem_id,
+        channel_id: row.try_get("channel_id")?,
+        message_id: row.try_get("message_id")?,
+        posted_at: row.try_get("posted_at")?,
+    }))
+}
+
+// Extra coins earned by solving a daily problem before the next one was posted
+// (0 when the problem wasn't the daily at `solved_at`). Claims made after the next
+// post still count, so late `!solved` runs and polling delays don't lose the bonus.
+pub async fn daily_bonus(
+    db: &PgPool,
+    guild_id: i64,
+    problem_id: &ProblemId,
+    solved_at: i64,
+) -> Result<i64, sqlx::Error> {
+    let windows = daily_windows(db, guild_id, problem_id).await?;
+    if !solved_while_daily(&windows, solved_at) {
+        return Ok(0);
+    }
+
+    let bonus = sqlx::query_scalar::<_, Option<i32>>(
+        "SELECT daily_bonus FROM guild_config WHERE guild_id = $1",
+    )
+    .bind(guild_id)
+    .fetch_optional(db)
+    .await?
+    .flatten()
+    .unwrap_or(DEFAULT_DAILY_BONUS);
+
+    Ok(bonus.max(0) as i64)
+}
+
+// Every window in which the problem was the guild's daily (usually at most one)
+async fn daily_windows(
+    db: &PgPool,
+    guild_id: i64,
+    problem_id: &ProblemId,
+) -> Result<Vec<DailyWindow>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (DateTime<Utc>, Option<DateTime<Utc>>)>(
+        "SELECT posted_at, replaced_at
+        FROM (
+            SELECT problem_id, posted_at,
+                LEAD(posted_at) OVER (ORDER BY posted_at) AS replaced_at
+            FROM daily_problems
+            WHERE guild_id = $1
+        ) dailies
+        WHERE problem_id = $2",
+    )
+    .bind(guild_id)
+    .bind(problem_id.to_string())
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(posted_at, replaced_at)| DailyWindow {
+            posted_at: posted_at.timestamp(),
+            replaced_at: replaced_at.map(|replaced_at| replaced_at.timestamp()),
+        })
+        .collect())
+}
+
+// Whether a submission at `solved_at` (unix seconds) was made while the problem was the daily
+fn solved_while_daily(windows: &[DailyWindow], solved_at: i64) -> bool {
+    windows.iter().any(|window| window.contains(solved_at))
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::problem;
+
+    fn candidate_ids(problems: &[Problem], exclusions: &DailyExclusions) -> Vec<String> {
+        daily_candidates(problems, exclusions)
+            .into_iter()
+            .map(|problem| problem.index.clone())
+            .collect()
+    }
+
+    #[test]
+    fn candidates_fall_back_when_exclusions_leave_nothing() {
+        let problems = [problem(4, "A"), problem(4, "B"), problem(4, "C")];
+        let mut exclusions = DailyExclusions {
+            posted: HashSet::from(["4A".to_string()]),
+            widely_solved: HashSet::from(["4B".to_string()]),
+        };
+        assert_eq!(candidate_ids(&problems, &exclusions), ["C"]);
+
+        // Only widely solved problems left: they are used rather than repeats
+        exclusions.posted.insert("4C".to_string());
+        assert_eq!(candidate_ids(&problems, &exclusions), ["B"]);
+
+        // Everything was posted already: start over with the whole range
+        exclusions.posted.insert("4B".to_string());
+        assert_eq!(candidate_ids(&problems, &exclusions), ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn solves_count_only_while_the_problem_was_the_daily() {
+        let day = 24 * 60 * 60;
+        let posted_at = 1_700_000_000;
+        let windows = [DailyWindow {
+            posted_at,
+            replaced_at: Some(posted_at + day),
+        }];
+
+        assert!(!solved_while_daily(&windows, posted_at - 1));
+        assert!(solved_while_daily(&windows, posted_at));
+        // Accepted an hour before the next post (and possibly claimed after it)
+        assert!(solved_while_daily(&windows, posted_at + day - 3600));
+        assert!(solved_while_daily(&windows, posted_at + day - 1));
+        assert!(!solved_while_daily(&windows, posted_at + day));
+        assert!(!solved_while_daily(&[], posted_at));
+    }
+
+    #[test]
+    fn the_current_daily_stays_open_until_the_next_post() {
+        let posted_at = 1_700_000_000;
+        let windows = [
+            DailyWindow {
+                posted_at: posted_at - 30 * 24 * 60 * 60,
+                replaced_at: Some(posted_at - 29 * 24 * 60 * 60),
+            },
+            DailyWindow {
+                posted_at,
+                replaced_at: None,
+            },
+        ];
+
+        assert!(solved_while_daily(&windows, posted_at + 7 * 24 * 60 * 60));
+        assert!(solved_while_daily(&windows, posted_at - 30 * 24 * 60 * 60));
+        assert!(!solved_while_daily(&windows, posted_at - 1));
+    }
+}
@@ -4,3 +4,6 @@
 
 // Module declarations for background task functionality
 pub mod daily; // Daily scheduled tasks (problem posting, notifications, etc.)
+pub mod problem_cache; // Periodic refresh of the cached Codeforces problemset
+pub mod ratings; // Batched refresh of linked members' Codeforces ratings
+pub mod solves; // Polling of linked handles' submissions to detect new solves
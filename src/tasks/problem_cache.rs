@@ -0,0 +1,33 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::Bot;
+use std::sync::Arc;
+
+// =====================
+//   Problemset Refresh Task
+// =====================
+
+// How often the task checks whether the cached problemset has expired
+const CHECK_INTERVAL_SECS: u64 = 5 * 60;
+
+// Background task - keeps the problemset cache fresh without blocking commands
+pub async fn start_problem_cache_task(bot: Arc<Bot>) {
+    println!(
+        "Problem cache task started (TTL: {}s)",
+        bot.problem_cache.ttl().as_secs()
+    );
+
+    loop {
+        // Only download again once the snapshot is older than the TTL
+        if bot.problem_cache.is_stale().await {
+            match bot.problem_cache.refresh().await {
+                Ok(count) => println!("Problemset cache refreshed with {} problems", count),
+                Err(e) => println!("Error refreshing problemset cache: {}", e),
+            }
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(CHECK_INTERVAL_SECS)).await;
+    }
+}
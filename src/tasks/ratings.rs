@@ -0,0 +1,95 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::Bot;
+use std::error::Error;
+use std::sync::Arc;
+
+// =====================
+//   Rating Refresh Task
+// =====================
+
+// How often every linked handle gets its rating refreshed (12 hours)
+const REFRESH_INTERVAL_SECS: u64 = 12 * 60 * 60;
+
+// Background task - keeps codeforces_rating/codeforces_rank of linked users up to date
+pub async fn start_rating_refresh_task(bot: Arc<Bot>) {
+    println!("Rating refresh task started");
+
+    loop {
+        match refresh_linked_ratings(&bot, None).await {
+            Ok(updated) => println!("Refreshed Codeforces ratings of {} members", updated),
+            Err(e) => println!("Error refreshing Codeforces ratings: {}", e),
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(REFRESH_INTERVAL_SECS)).await;
+    }
+}
+
+// =====================
+//   Batched Rating Refresh
+// =====================
+
+// Refresh the stored rating and rank of every linked member (of one guild, or of all guilds)
+// using batched user.info calls. Returns the number of user_info rows updated.
+pub async fn refresh_linked_ratings(
+    bot: &Bot,
+    guild_id: Option<i64>,
+) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    // =====================
+    //   Collect Linked Handles
+    // =====================
+
+    let handles = sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT codeforces_handle FROM user_info
+        WHERE codeforces_handle IS NOT NULL
+        AND ($1::BIGINT IS NULL OR guild_id = $1)",
+    )
+    .bind(guild_id)
+    .fetch_all(&bot.db)
+    .await?;
+
+    if handles.is_empty() {
+        return Ok(0);
+    }
+
+    // =====================
+    //   Codeforces API Integration
+    // =====================
+
+    let users = bot.codeforces.get_users_info(&handles).await?;
+
+    // =====================
+    //   Database Update
+    // =====================
+
+    let mut updated = 0;
+    let mut tx = bot.db.begin().await?;
+
+    for handle in &handles {
+        let Some(user) = users.get(&handle.to_lowercase()) else {
+            continue;
+        };
+
+        let result = sqlx::query(
+            "UPDATE user_info
+            SET codeforces_rating = $1, codeforces_rank = $2, codeforces_max_rating = $3
+            WHERE codeforces_handle = $4
+            AND ($5::BIGINT IS NULL OR guild_id = $5)",
+        )
+        .bind(user.rating.unwrap_or(0))
+        .bind(user.rank.as_deref().unwrap_or("unrated"))
+        .bind(user.max_rating.or(user.rating).unwrap_or(0))
+        .bind(handle)
+        .bind(guild_id)
+        .execute(&mut *tx)
+        .await?;
+
+        updated += result.rows_affected();
+    }
+
+    tx.commit().await?;
+
+    Ok(updated)
+}
@@ -0,0 +1,421 @@
+// =====================
+//   Imports and Dependencies
+// =====================
+
+use crate::api::codeforces::{CodeforcesClient, CodeforcesError, Submission};
+use crate::api::problem_id::ProblemId;
+use crate::commands::codeforces::solved::SOLVE_REWARD;
+use crate::i18n::tr_args;
+use crate::tasks::daily::daily_bonus;
+use crate::Bot;
+use serenity::builder::CreateMessage;
+use serenity::http::Http;
+use serenity::model::id::{ChannelId, GuildId};
+use std::error::Error;
+use std::sync::Arc;
+
+// How often linked handles are checked for new submissions (5 minutes)
+const POLL_INTERVAL_SECS: u64 = 5 * 60;
+
+// Submissions requested per user.status call
+const SUBMISSIONS_PAGE_SIZE: u32 = 50;
+
+// =====================
+//   Solve Polling Task
+// =====================
+
+// Background task - detects accepted submissions of linked handles and records them as solves
+pub async fn start_solve_polling_task(bot: Arc<Bot>, http: Arc<Http>) {
+    println!("Solve polling task started");
+
+    loop {
+        match poll_linked_handles(&bot, &http).await {
+            Ok(0) => {}
+            Ok(solves) => println!("Detected {} new solves", solves),
+            Err(e) => println!("Error polling Codeforces submissions: {}", e),
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(POLL_INTERVAL_SECS)).await;
+    }
+}
+
+// =====================
+//   Handle Polling
+// =====================
+
+// Problem a handle got accepted since the last poll
+#[derive(Debug, Clone, PartialEq)]
+struct NewSolve {
+    problem_id: ProblemId,
+    name: String,
+    // Time of the first accepted submission (unix seconds)
+    solved_at: i64,
+}
+
+// Solve recorded for one member of one guild
+struct RecordedSolve {
+    guild_id: i64,
+    user_id: i64,
+    solve: NewSolve,
+    coins: i64,
+}
+
+// Check every linked handle once. Returns the number of solves recorded.
+async fn poll_linked_handles(
+    bot: &Bot,
+    http: &Http,
+) -> Result<usize, Box<dyn Error + Send + Sync>> {
+    let handles = sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT LOWER(codeforces_handle) FROM user_info
+        WHERE codeforces_handle IS NOT NULL",
+    )
+    .fetch_all(&bot.db)
+    .await?;
+
+    let mut recorded = 0;
+
+    for handle in handles {
+        // One failing handle (renamed, API hiccup) shouldn't stop the others
+        match poll_handle(bot, &handle).await {
+            Ok(solves) => {
+                recorded += solves.len();
+                announce(bot, http, &solves).await;
+            }
+            Err(e) => println!("Error polling submissions of {}: {}", handle, e),
+        }
+    }
+
+    Ok(recorded)
+}
+
+// Record the new accepted submissions of one handle for every member linked to it
+async fn poll_handle(
+    bot: &Bot,
+    handle: &str,
+) -> Result<Vec<RecordedSolve>, Box<dyn Error + Send + Sync>> {
+    let last_seen = sqlx::query_scalar::<_, i64>(
+        "SELECT last_submission_id FROM submission_cursor WHERE codeforces_handle = $1",
+    )
+    .bind(handle)
+    .fetch_optional(&bot.db)
+    .await?;
+
+    // =====================
+    //   Fetch New Submissions
+    // =====================
+
+    let submissions = match last_seen {
+        Some(last_seen) => {
+            fetch_submissions_after(&bot.codeforces, handle, last_seen as u64).await?
+        }
+        // A handle seen for the first time only sets the cursor;
+        // its history is imported by `!sync`, not rewarded here
+        None => {
+            bot.codeforces
+                .get_user_status(handle, None, Some(1))
+                .await?
+        }
+    };
+
+    // A handle without any submission starts at 0, so its first one is rewarded
+    let newest = match submissions.iter().map(|submission| submission.id).max() {
+        Some(newest) => newest,
+        None if last_seen.is_some() => return Ok(Vec::new()),
+        None => 0,
+    };
+    let solves = if last_seen.is_some() {
+        accepted_problems(&submissions)
+    } else {
+        Vec::new()
+    };
+
+    // =====================
+    //   Database Update
+    // =====================
+
+    let members = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT guild_id, user_id FROM user_info WHERE LOWER(codeforces_handle) = $1",
+    )
+    .bind(handle)
+    .fetch_all(&bot.db)
+    .await?;
+
+    let mut recorded = Vec::new();
+    let mut tx = bot.db.begin().await?;
+
+    for (guild_id, user_id) in members {
+        for solve in &solves {
+            // Problems already verified through `!solved` are neither stored nor rewarded twice
+            let inserted = sqlx::query(
+                "INSERT INTO user_solved_problem (guild_id, user_id, problem_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT DO NOTHING",
+            )
+            .bind(guild_id)
+            .bind(user_id)
+            .bind(solve.problem_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+
+            if inserted.rows_affected() == 0 {
+                continue;
+            }
+
+            // Same daily problem bonus as `!solved`
+            let coins = SOLVE_REWARD
+                + daily_bonus(&bot.db, guild_id, &solve.problem_id, solve.solved_at).await?;
+
+            sqlx::query(
+                "UPDATE user_info SET coins = coins + $3 WHERE guild_id = $1 AND user_id = $2",
+            )
+            .bind(guild_id)
+            .bind(user_id)
+            .bind(coins)
+            .execute(&mut *tx)
+            .await?;
+
+            recorded.push(RecordedSolve {
+                guild_id,
+                user_id,
+                solve: solve.clone(),
+                coins,
+            });
+        }
+    }
+
+    // Move the cursor in the same transaction, so a failed poll is retried as a whole
+    sqlx::query(
+        "INSERT INTO submission_cursor (codeforces_handle, last_submission_id)
+        VALUES ($1, $2)
+        ON CONFLICT (codeforces_handle)
+        DO UPDATE SET last_submission_id = GREATEST(submission_cursor.last_submission_id, EXCLUDED.last_submission_id)",
+    )
+    .bind(handle)
+    .bind(newest as i64)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(recorded)
+}
+
+// Submissions newer than `last_seen`, paging back through the newest-first history.
+// Paging goes on until `last_seen` is reached, since the cursor moves past everything returned.
+async fn fetch_submissions_after(
+    codeforces: &CodeforcesClient,
+    handle: &str,
+    last_seen: u64,
+) -> Result<Vec<Submission>, CodeforcesError> {
+    let mut submissions = Vec::new();
+    let mut from = 1;
+
+    loop {
+        let batch = codeforces
+            .get_user_status(handle, Some(from), Some(SUBMISSIONS_PAGE_SIZE))
+            .await?;
+
+        let reached_last_seen = batch.iter().any(|submission| submission.id <= last_seen);
+        let is_last_page = batch.len() < SUBMISSIONS_PAGE_SIZE as usize;
+
+        submissions.extend(
+            batch
+                .into_iter()
+                .filter(|submission| submission.id > last_seen),
+        );
+
+        if reached_last_seen || is_last_page {
+            break;
+        }
+        from += SUBMISSIONS_PAGE_SIZE;
+    }
+
+    Ok(submissions)
+}
+
+// Distinct accepted problems among the submissions, oldest first
+fn accepted_problems(submissions: &[Submission]) -> Vec<NewSolve> {
+    let mut solves: Vec<NewSolve> = Vec::new();
+
+    for submission in submissions.iter().rev() {
+        if !submission.is_accepted() {
+            continue;
+        }
+        let Some(problem_id) = submission.problem.id() else {
+            continue;
+        };
+
+        // Accepted again later: the first accepted submission is when it was solved
+        if solves.iter().any(|solve| solve.problem_id == problem_id) {
+            continue;
+        }
+
+        solves.push(NewSolve {
+            problem_id,
+            name: submission.problem.name.clone(),
+            solved_at: submission.creation_time_seconds,
+        });
+    }
+
+    solves
+}
+
+// =====================
+//   Solve Announcements
+// =====================
+
+// Post recorded solves in the solve channel of guilds that configured one
+async fn announce(bot: &Bot, http: &Http, solves: &[RecordedSolve]) {
+    for recorded in solves {
+        let channel_id = match sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT solve_channel_id FROM guild_config WHERE guild_id = $1",
+        )
+        .bind(recorded.guild_id)
+        .fetch_optional(&bot.db)
+        .await
+        {
+            Ok(Some(Some(channel_id))) if channel_id > 0 => ChannelId::new(channel_id as u64),
+            Ok(_) => continue,
+            Err(e) => {
+                println!(
+                    "Error reading solve channel of guild {}: {}",
+                    recorded.guild_id, e
+                );
+                continue;
+            }
+        };
+
+        let settings = bot
+            .settings
+            .get(Some(GuildId::new(recorded.guild_id as u64)))
+            .await;
+        let solve = &recorded.solve;
+
+        let message = CreateMessage::new().content(tr_args(
+            settings.language,
+            "solves.announcement",
+            &[
+                ("user", &recorded.user_id),
+                ("problem", &solve.problem_id),
+                ("name", &solve.name),
+                ("url", &solve.problem_id.url()),
+                ("coins", &recorded.coins),
+            ],
+        ));
+
+        if let Err(why) = channel_id.send_message(http, message).await {
+            println!(
+                "Error announcing solve in guild {}: {:?}",
+                recorded.guild_id, why
+            );
+        }
+    }
+}
+
+// =====================
+//   Tests
+// =====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::codeforces::builders::{mock_config, problem, submission};
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    // user.status response holding the submissions with the given ids (newest first)
+    fn status_page(ids: impl Iterator<Item = u64>) -> ResponseTemplate {
+        let result: Vec<_> = ids
+            .map(|id| {
+                json!({
+                    "id": id,
+                    "contestId": 4,
+                    "creationTimeSeconds": 1_700_000_000 + id,
+                    "problem": {
+                        "contestId": 4,
+                        "index": "A",
+                        "name": "Watermelon",
+                        "type": "PROGRAMMING"
+                    },
+                    "author": {"members": [{"handle": "someone"}], "participantType": "PRACTICE"},
+                    "programmingLanguage": "GNU C++17",
+                    "verdict": "OK"
+                })
+            })
+            .collect();
+
+        ResponseTemplate::new(200).set_body_json(json!({"status": "OK", "result": result}))
+    }
+
+    // Serve a history of submissions 1..=total, newest first, 50 per page
+    async fn mount_history(server: &MockServer, total: u64) {
+        for from in (1..=total).step_by(SUBMISSIONS_PAGE_SIZE as usize) {
+            let newest = total - (from - 1);
+            let oldest = newest
+                .saturating_sub(SUBMISSIONS_PAGE_SIZE as u64 - 1)
+                .max(1);
+
+            Mock::given(method("GET"))
+                .and(path("/user.status"))
+                .and(query_param("from", from.to_string()))
+                .and(query_param("count", SUBMISSIONS_PAGE_SIZE.to_string()))
+                .respond_with(status_page((oldest..=newest).rev()))
+                .mount(server)
+                .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_back_until_the_cursor_is_reached() {
+        let server = MockServer::start().await;
+        mount_history(&server, 300).await;
+        let codeforces = CodeforcesClient::new(mock_config(server.uri())).unwrap();
+
+        // 220 new submissions span five pages
+        let submissions = fetch_submissions_after(&codeforces, "someone", 80)
+            .await
+            .unwrap();
+
+        let ids: Vec<u64> = submissions.iter().map(|submission| submission.id).collect();
+        assert_eq!(ids, (81..=300).rev().collect::<Vec<_>>());
+        assert_eq!(server.received_requests().await.unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn stops_at_the_end_of_a_short_history() {
+        let server = MockServer::start().await;
+        mount_history(&server, 30).await;
+        let codeforces = CodeforcesClient::new(mock_config(server.uri())).unwrap();
+
+        let submissions = fetch_submissions_after(&codeforces, "someone", 0)
+            .await
+            .unwrap();
+
+        assert_eq!(submissions.len(), 30);
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn keeps_each_accepted_problem_once() {
+        // Newest first, as returned by user.status
+        let submissions = [
+            submission(5, problem(4, "A"), "OK", 1_700_000_005),
+            submission(4, problem(4, "B"), "WRONG_ANSWER", 1_700_000_004),
+            submission(3, problem(4, "A"), "OK", 1_700_000_003),
+            submission(2, problem(4, "C"), "OK", 1_700_000_002),
+        ];
+
+        let problems: Vec<(String, i64)> = accepted_problems(&submissions)
+            .into_iter()
+            .map(|solve| (solve.problem_id.to_string(), solve.solved_at))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                ("4C".to_string(), 1_700_000_002),
+                ("4A".to_string(), 1_700_000_003)
+            ]
+        );
+    }
+}